@@ -0,0 +1,114 @@
+use crate::{ AudioBuffer, AudioBufferRef };
+use std::collections::VecDeque;
+
+
+
+impl AudioBufferRef<'_> {
+
+	/// Get the highest absolute sample value.
+	pub fn peak(&self) -> f32 {
+		self.data.iter().fold(0.0, |peak, sample| sample.abs().max(peak))
+	}
+
+	/// Get the root mean square of all samples.
+	pub fn rms(&self) -> f32 {
+		if self.data.is_empty() {
+			0.0
+		} else {
+			(self.data.iter().map(|sample| sample * sample).sum::<f32>() / self.data.len() as f32).sqrt()
+		}
+	}
+
+	/// Get the highest absolute sample value in decibels relative to full scale.
+	pub fn peak_dbfs(&self) -> f32 {
+		gain_to_decibels(self.peak())
+	}
+
+	/// Get the root mean square of all samples in decibels relative to full scale.
+	pub fn rms_dbfs(&self) -> f32 {
+		gain_to_decibels(self.rms())
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Get the highest absolute sample value.
+	pub fn peak(&self) -> f32 {
+		self.as_view().peak()
+	}
+
+	/// Get the root mean square of all samples.
+	pub fn rms(&self) -> f32 {
+		self.as_view().rms()
+	}
+
+	/// Get the highest absolute sample value in decibels relative to full scale.
+	pub fn peak_dbfs(&self) -> f32 {
+		self.as_view().peak_dbfs()
+	}
+
+	/// Get the root mean square of all samples in decibels relative to full scale.
+	pub fn rms_dbfs(&self) -> f32 {
+		self.as_view().rms_dbfs()
+	}
+}
+
+
+
+/// Convert decibels to a linear gain.
+pub(crate) fn decibels_to_gain(decibels:f32) -> f32 {
+	10.0_f32.powf(decibels / 20.0)
+}
+
+/// Convert a linear gain to decibels. A gain of zero results in negative infinity.
+pub(crate) fn gain_to_decibels(gain:f32) -> f32 {
+	20.0 * gain.abs().log10()
+}
+
+/// Check if the sum of all channels crosses zero at the given frame of interleaved data, meaning it is zero or has a different sign than the previous frame.
+pub(crate) fn is_zero_crossing(data:&[f32], channel_count:usize, frame_index:usize) -> bool {
+	let frame_sum = |frame_index:usize| data[frame_index * channel_count..(frame_index + 1) * channel_count].iter().sum::<f32>();
+	let current:f32 = frame_sum(frame_index);
+	current == 0.0 || (frame_index > 0 && (frame_sum(frame_index - 1) < 0.0) != (current < 0.0))
+}
+
+/// Get the root mean square of every frame of interleaved data, measured over all channels in a window centered on the frame.
+pub(crate) fn windowed_rms(data:&[f32], channel_count:usize, window_frame_count:usize) -> Vec<f32> {
+	let frame_count:usize = data.len() / channel_count;
+	let mut energy_sums:Vec<f64> = Vec::with_capacity(frame_count + 1);
+	energy_sums.push(0.0);
+	for frame in data.chunks_exact(channel_count) {
+		energy_sums.push(energy_sums[energy_sums.len() - 1] + frame.iter().map(|sample| (*sample as f64) * (*sample as f64)).sum::<f64>());
+	}
+	let half_window_frame_count:usize = window_frame_count.max(1) / 2;
+	(0..frame_count).map(|frame_index| {
+		let start:usize = frame_index.saturating_sub(half_window_frame_count);
+		let end:usize = (frame_index + half_window_frame_count + 1).min(frame_count);
+		((energy_sums[end] - energy_sums[start]).max(0.0) / ((end - start) * channel_count) as f64).sqrt() as f32
+	}).collect()
+}
+
+/// Get the highest absolute sample value around every frame of interleaved data, measured over all channels in a window centered on the frame.
+pub(crate) fn windowed_peak(data:&[f32], channel_count:usize, window_frame_count:usize) -> Vec<f32> {
+	let frame_peaks:Vec<f32> = data.chunks_exact(channel_count).map(|frame| frame.iter().fold(0.0, |peak, sample| sample.abs().max(peak))).collect();
+	let half_window_frame_count:usize = window_frame_count.max(1) / 2;
+
+	// Keep the indexes of decreasing peaks within the window, so the front always holds the highest peak.
+	let mut window:VecDeque<usize> = VecDeque::new();
+	let mut next_frame_index:usize = 0;
+	(0..frame_peaks.len()).map(|frame_index| {
+		while next_frame_index < frame_peaks.len() && next_frame_index <= frame_index + half_window_frame_count {
+			while window.back().map(|index| frame_peaks[*index] <= frame_peaks[next_frame_index]).unwrap_or(false) {
+				window.pop_back();
+			}
+			window.push_back(next_frame_index);
+			next_frame_index += 1;
+		}
+		while window.front().map(|index| *index + half_window_frame_count < frame_index).unwrap_or(false) {
+			window.pop_front();
+		}
+		frame_peaks[window[0]]
+	}).collect()
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod test {
+	use crate::AudioBuffer;
+
+
+
+	#[test]
+	fn peak() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.1, -0.6, 0.5, 0.2], 2, 1000);
+		assert_eq!(buffer.peak(), 0.6);
+		assert_eq!(buffer.view_frames(1..2).peak(), 0.5);
+		assert_eq!(AudioBuffer::new(Vec::new(), 2, 1000).peak(), 0.0);
+	}
+
+	#[test]
+	fn rms() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.5, -0.5, 0.5, -0.5, 0.0, 0.0], 2, 1000);
+		assert_eq!(buffer.rms(), (1.0_f32 / 6.0).sqrt());
+		assert_eq!(buffer.view_frames(0..2).rms(), 0.5);
+		assert_eq!(AudioBuffer::new(Vec::new(), 2, 1000).rms(), 0.0);
+	}
+
+	#[test]
+	fn decibels_relative_to_full_scale() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.5, -0.5, 0.5, -0.5], 2, 1000);
+		assert!((buffer.peak_dbfs() + 6.0206).abs() < 0.0001);
+		assert!((buffer.rms_dbfs() + 6.0206).abs() < 0.0001);
+		assert_eq!(AudioBuffer::new(vec![0.0; 4], 2, 1000).peak_dbfs(), f32::NEG_INFINITY);
+	}
+}
@@ -0,0 +1,145 @@
+use crate::AudioBuffer;
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Speaker {
+	FrontLeft,
+	FrontRight,
+	FrontCenter,
+	LowFrequency,
+	BackLeft,
+	BackRight,
+	FrontLeftOfCenter,
+	FrontRightOfCenter,
+	BackCenter,
+	SideLeft,
+	SideRight,
+	TopCenter,
+	TopFrontLeft,
+	TopFrontCenter,
+	TopFrontRight,
+	TopBackLeft,
+	TopBackCenter,
+	TopBackRight
+}
+impl Speaker {
+
+	/// All speakers in the order of their WAV channel mask bits.
+	pub const ALL:[Speaker; 18] = [
+		Speaker::FrontLeft, Speaker::FrontRight, Speaker::FrontCenter, Speaker::LowFrequency, Speaker::BackLeft, Speaker::BackRight,
+		Speaker::FrontLeftOfCenter, Speaker::FrontRightOfCenter, Speaker::BackCenter, Speaker::SideLeft, Speaker::SideRight, Speaker::TopCenter,
+		Speaker::TopFrontLeft, Speaker::TopFrontCenter, Speaker::TopFrontRight, Speaker::TopBackLeft, Speaker::TopBackCenter, Speaker::TopBackRight
+	];
+
+	/// Get the bit representing this speaker in a WAV channel mask.
+	pub fn mask_bit(&self) -> u32 {
+		1 << Speaker::ALL.iter().position(|speaker| speaker == self).unwrap_or_default()
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ChannelLayout {
+	Mono,
+	Stereo,
+	Lcr,
+	Quad,
+	Surround51,
+	Surround71,
+	Custom(Vec<Speaker>)
+}
+impl ChannelLayout {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Get the most common layout for the given channel count, if there is one.
+	pub fn default_for_channel_count(channel_count:usize) -> Option<ChannelLayout> {
+		match channel_count {
+			1 => Some(ChannelLayout::Mono),
+			2 => Some(ChannelLayout::Stereo),
+			3 => Some(ChannelLayout::Lcr),
+			4 => Some(ChannelLayout::Quad),
+			6 => Some(ChannelLayout::Surround51),
+			8 => Some(ChannelLayout::Surround71),
+			_ => None
+		}
+	}
+
+	/// Create a layout from a WAV channel mask. Returns None for an empty mask.
+	pub fn from_mask(mask:u32) -> Option<ChannelLayout> {
+		if mask == 0 {
+			return None;
+		}
+		let speakers:Vec<Speaker> = Speaker::ALL.iter().filter(|speaker| mask & speaker.mask_bit() != 0).cloned().collect();
+		Some(ChannelLayout::from_speakers(speakers))
+	}
+
+	/// Create a layout from a list of speakers, one for each channel. Recognizes the named layouts.
+	pub fn from_speakers(speakers:Vec<Speaker>) -> ChannelLayout {
+		[ChannelLayout::Mono, ChannelLayout::Stereo, ChannelLayout::Lcr, ChannelLayout::Quad, ChannelLayout::Surround51, ChannelLayout::Surround71]
+			.into_iter()
+			.find(|layout| layout.speakers() == speakers)
+			.unwrap_or(ChannelLayout::Custom(speakers))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the speaker of each channel.
+	pub fn speakers(&self) -> Vec<Speaker> {
+		use Speaker::*;
+		match self {
+			ChannelLayout::Mono => vec![FrontCenter],
+			ChannelLayout::Stereo => vec![FrontLeft, FrontRight],
+			ChannelLayout::Lcr => vec![FrontLeft, FrontRight, FrontCenter],
+			ChannelLayout::Quad => vec![FrontLeft, FrontRight, BackLeft, BackRight],
+			ChannelLayout::Surround51 => vec![FrontLeft, FrontRight, FrontCenter, LowFrequency, BackLeft, BackRight],
+			ChannelLayout::Surround71 => vec![FrontLeft, FrontRight, FrontCenter, LowFrequency, BackLeft, BackRight, SideLeft, SideRight],
+			ChannelLayout::Custom(speakers) => speakers.clone()
+		}
+	}
+
+	/// Get the amount of channels in the layout.
+	pub fn channel_count(&self) -> usize {
+		self.speakers().len()
+	}
+
+	/// Get the index of the channel for the given speaker.
+	pub fn channel_index(&self, speaker:Speaker) -> Option<usize> {
+		self.speakers().iter().position(|channel_speaker| *channel_speaker == speaker)
+	}
+
+	/// Get the WAV channel mask of the layout. Returns None if the layout can not be represented by a mask, which requires channels in mask bit order without duplicates.
+	pub fn mask(&self) -> Option<u32> {
+		let bits:Vec<u32> = self.speakers().iter().map(|speaker| speaker.mask_bit()).collect();
+		if bits.windows(2).all(|pair| pair[0] < pair[1]) {
+			Some(bits.iter().fold(0, |mask, bit| mask | bit))
+		} else {
+			None
+		}
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Get the channel layout of the buffer, if it is known.
+	pub fn channel_layout(&self) -> Option<&ChannelLayout> {
+		self.channel_layout.as_ref()
+	}
+
+	/// Set the channel layout of the buffer.
+	pub fn set_channel_layout(&mut self, channel_layout:Option<ChannelLayout>) {
+		self.channel_layout = channel_layout;
+	}
+
+	/// Return the buffer with the given channel layout.
+	pub fn with_channel_layout(mut self, channel_layout:ChannelLayout) -> Self {
+		self.channel_layout = Some(channel_layout);
+		self
+	}
+}
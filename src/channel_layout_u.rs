@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferError, ChannelLayout, Speaker };
+
+
+
+	#[test]
+	fn named_layout_masks() {
+		assert_eq!(ChannelLayout::Mono.mask(), Some(0x4));
+		assert_eq!(ChannelLayout::Stereo.mask(), Some(0x3));
+		assert_eq!(ChannelLayout::Lcr.mask(), Some(0x7));
+		assert_eq!(ChannelLayout::Quad.mask(), Some(0x33));
+		assert_eq!(ChannelLayout::Surround51.mask(), Some(0x3F));
+		assert_eq!(ChannelLayout::Surround71.mask(), Some(0x63F));
+	}
+
+	#[test]
+	fn from_mask() {
+		assert_eq!(ChannelLayout::from_mask(0), None);
+		assert_eq!(ChannelLayout::from_mask(0x3F), Some(ChannelLayout::Surround51));
+		assert_eq!(ChannelLayout::from_mask(0x60F), Some(ChannelLayout::Custom(vec![Speaker::FrontLeft, Speaker::FrontRight, Speaker::FrontCenter, Speaker::LowFrequency, Speaker::SideLeft, Speaker::SideRight])));
+	}
+
+	#[test]
+	fn custom_layout() {
+		let layout:ChannelLayout = ChannelLayout::from_speakers(vec![Speaker::FrontRight, Speaker::FrontLeft]);
+		assert_eq!(layout.channel_count(), 2);
+		assert_eq!(layout.channel_index(Speaker::FrontLeft), Some(1));
+		assert_eq!(layout.mask(), None);
+		assert_eq!(ChannelLayout::from_speakers(vec![Speaker::FrontLeft, Speaker::FrontRight]), ChannelLayout::Stereo);
+	}
+
+	#[test]
+	fn default_for_channel_count() {
+		assert_eq!(ChannelLayout::default_for_channel_count(6), Some(ChannelLayout::Surround51));
+		assert_eq!(ChannelLayout::default_for_channel_count(5), None);
+	}
+
+	#[test]
+	fn validate_layout() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 12], 2, 1000);
+		assert_eq!(buffer.clone().with_channel_layout(ChannelLayout::Stereo).validate(), Ok(()));
+		assert_eq!(buffer.with_channel_layout(ChannelLayout::Surround51).validate(), Err(AudioBufferError::LayoutMismatch { layout_channel_count: 6, channel_count: 2 }));
+	}
+}
@@ -0,0 +1,163 @@
+use crate::{ AudioBuffer, AudioBufferError, ChannelLayout, Speaker };
+use std::f32::consts::FRAC_1_SQRT_2;
+
+
+
+const MINUS_3_DB:f32 = FRAC_1_SQRT_2;
+const MINUS_6_DB:f32 = 0.5;
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChannelMatrix {
+	source_channel_count:usize,
+	target_channel_count:usize,
+	coefficients:Vec<f32>,
+	target_layout:Option<ChannelLayout>
+}
+impl ChannelMatrix {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a matrix from a list of rows, one for each target channel. Each row holds the gain of every source channel in that target channel. Shorter rows are padded with silence.
+	pub fn new(rows:Vec<Vec<f32>>) -> ChannelMatrix {
+		let source_channel_count:usize = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+		ChannelMatrix {
+			source_channel_count,
+			target_channel_count: rows.len(),
+			coefficients: rows.into_iter().flat_map(|row| { let padding:usize = source_channel_count - row.len(); row.into_iter().chain(vec![0.0; padding]) }).collect(),
+			target_layout: None
+		}
+	}
+
+	/// Create a matrix that keeps all channels as they are.
+	pub fn identity(channel_count:usize) -> ChannelMatrix {
+		ChannelMatrix::new((0..channel_count).map(|target_index| (0..channel_count).map(|source_index| if source_index == target_index { 1.0 } else { 0.0 }).collect()).collect())
+	}
+
+	/// Create a matrix between two channel counts without knowing the speaker positions. Downmixes between counts with a common layout use the standard matrix between those layouts, other downmixes average every source channel into the target channel at its index modulo the target count, so no channel is dropped. Upmixes repeat the source channels.
+	pub fn by_count(source_channel_count:usize, target_channel_count:usize) -> ChannelMatrix {
+		if target_channel_count < source_channel_count {
+			return match (ChannelLayout::default_for_channel_count(source_channel_count), ChannelLayout::default_for_channel_count(target_channel_count)) {
+				(Some(source_layout), Some(target_layout)) => ChannelMatrix { target_layout: None, ..ChannelMatrix::standard(&source_layout, &target_layout) },
+				_ => ChannelMatrix::new((0..target_channel_count).map(|target_index| {
+					let folded_channel_count:usize = (target_index..source_channel_count).step_by(target_channel_count).count();
+					(0..source_channel_count).map(|source_index| if source_index % target_channel_count == target_index { 1.0 / folded_channel_count as f32 } else { 0.0 }).collect()
+				}).collect())
+			};
+		}
+		ChannelMatrix::new((0..target_channel_count).map(|target_index|
+			(0..source_channel_count).map(|source_index| if source_index == target_index % source_channel_count { 1.0 } else { 0.0 }).collect()
+		).collect())
+	}
+
+	/// Create the standard matrix between two channel layouts. Speakers present in both layouts are copied. Other speakers are folded into the nearest available speakers following ITU-R BS.775, for example center and surrounds into the front pair at -3 dB. The low frequency channel is dropped when the target has none.
+	pub fn standard(source_layout:&ChannelLayout, target_layout:&ChannelLayout) -> ChannelMatrix {
+		let target_speakers:Vec<Speaker> = target_layout.speakers();
+		let mut rows:Vec<Vec<f32>> = vec![vec![0.0; source_layout.channel_count()]; target_speakers.len()];
+		for (source_index, source_speaker) in source_layout.speakers().into_iter().enumerate() {
+			let routes:Vec<(Speaker, f32)> = if target_speakers.contains(&source_speaker) {
+				vec![(source_speaker, 1.0)]
+			} else {
+				speaker_fallbacks(source_speaker).into_iter().find(|routes| routes.iter().all(|(speaker, _)| target_speakers.contains(speaker))).unwrap_or_default()
+			};
+			for (target_speaker, gain) in routes {
+				if let Some(target_index) = target_layout.channel_index(target_speaker) {
+					rows[target_index][source_index] += gain;
+				}
+			}
+		}
+		ChannelMatrix {
+			target_layout: Some(target_layout.clone()),
+			..ChannelMatrix::new(rows)
+		}
+	}
+
+
+
+	/// Return the matrix scaled down so no target channel can exceed the loudest source channel. Rows whose absolute gains sum to more than 1.0 share the same scale, which keeps the balance between target channels.
+	pub fn normalized(mut self) -> Self {
+		let max_row_gain:f32 = self.coefficients.chunks(self.source_channel_count.max(1)).map(|row| row.iter().map(|gain| gain.abs()).sum::<f32>()).fold(0.0, f32::max);
+		if max_row_gain > 1.0 {
+			self.coefficients.iter_mut().for_each(|gain| *gain /= max_row_gain);
+		}
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the amount of channels the matrix expects.
+	pub fn source_channel_count(&self) -> usize {
+		self.source_channel_count
+	}
+
+	/// Get the amount of channels the matrix creates.
+	pub fn target_channel_count(&self) -> usize {
+		self.target_channel_count
+	}
+
+	/// Get the gain of a source channel in a target channel.
+	pub fn coefficient(&self, target_channel_index:usize, source_channel_index:usize) -> f32 {
+		self.coefficients[target_channel_index * self.source_channel_count + source_channel_index]
+	}
+
+	/// Get the layout of the created channels, if the matrix was created for a specific layout.
+	pub fn target_layout(&self) -> Option<&ChannelLayout> {
+		self.target_layout.as_ref()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Apply the matrix to interleaved data.
+	pub(crate) fn apply_interleaved(&self, data:&[f32]) -> Vec<f32> {
+		data.chunks(self.source_channel_count).flat_map(|source_frame|
+			self.coefficients.chunks(self.source_channel_count).map(|row| row.iter().zip(source_frame).map(|(gain, sample)| gain * sample).sum::<f32>()).collect::<Vec<f32>>()
+		).collect()
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Return the buffer with its channels mixed by the given matrix.
+	pub fn remixed(mut self, matrix:&ChannelMatrix) -> Result<Self, AudioBufferError> {
+		self.remix(matrix)?;
+		Ok(self)
+	}
+
+	/// Mix the channels of the buffer using the given matrix. The channel layout becomes the target layout of the matrix. Returns an error if the matrix expects a different channel count.
+	pub fn remix(&mut self, matrix:&ChannelMatrix) -> Result<(), AudioBufferError> {
+		if matrix.source_channel_count != self.channel_count {
+			return Err(AudioBufferError::MatrixMismatch { matrix_channel_count: matrix.source_channel_count, channel_count: self.channel_count });
+		}
+		Self::validate_settings(matrix.target_channel_count, self.sample_rate)?;
+		self.data = matrix.apply_interleaved(&self.data);
+		self.channel_count = matrix.target_channel_count;
+		self.channel_layout = matrix.target_layout.clone();
+		Ok(())
+	}
+}
+
+
+
+/// Get the alternatives to route a speaker to when the target layout does not have it, in order of preference. An alternative is only used if the target has all of its speakers.
+fn speaker_fallbacks(speaker:Speaker) -> Vec<Vec<(Speaker, f32)>> {
+	use Speaker::*;
+	match speaker {
+		FrontLeft | FrontRight => vec![vec![(FrontCenter, MINUS_3_DB)]],
+		FrontCenter | TopFrontCenter | TopCenter => vec![vec![(FrontCenter, 1.0)], vec![(FrontLeft, MINUS_3_DB), (FrontRight, MINUS_3_DB)]],
+		LowFrequency => Vec::new(),
+		FrontLeftOfCenter | TopFrontLeft => vec![vec![(FrontLeft, 1.0)], vec![(FrontCenter, MINUS_3_DB)]],
+		FrontRightOfCenter | TopFrontRight => vec![vec![(FrontRight, 1.0)], vec![(FrontCenter, MINUS_3_DB)]],
+		BackLeft | TopBackLeft => vec![vec![(BackLeft, 1.0)], vec![(SideLeft, 1.0)], vec![(FrontLeft, MINUS_3_DB)], vec![(FrontCenter, MINUS_6_DB)]],
+		BackRight | TopBackRight => vec![vec![(BackRight, 1.0)], vec![(SideRight, 1.0)], vec![(FrontRight, MINUS_3_DB)], vec![(FrontCenter, MINUS_6_DB)]],
+		SideLeft => vec![vec![(BackLeft, 1.0)], vec![(FrontLeft, MINUS_3_DB)], vec![(FrontCenter, MINUS_6_DB)]],
+		SideRight => vec![vec![(BackRight, 1.0)], vec![(FrontRight, MINUS_3_DB)], vec![(FrontCenter, MINUS_6_DB)]],
+		BackCenter | TopBackCenter => vec![vec![(BackCenter, 1.0)], vec![(BackLeft, MINUS_3_DB), (BackRight, MINUS_3_DB)], vec![(SideLeft, MINUS_3_DB), (SideRight, MINUS_3_DB)], vec![(FrontLeft, MINUS_3_DB), (FrontRight, MINUS_3_DB)], vec![(FrontCenter, MINUS_6_DB)]]
+	}
+}
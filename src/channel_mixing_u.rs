@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferError, ChannelLayout, ChannelMatrix, Speaker };
+	use std::f32::consts::FRAC_1_SQRT_2;
+
+
+
+	fn assert_rows(matrix:&ChannelMatrix, rows:&[&[f32]]) {
+		assert_eq!(matrix.target_channel_count(), rows.len());
+		for (target_index, row) in rows.iter().enumerate() {
+			assert_eq!(matrix.source_channel_count(), row.len());
+			for (source_index, coefficient) in row.iter().enumerate() {
+				assert!((matrix.coefficient(target_index, source_index) - coefficient).abs() < 0.0001, "coefficient {target_index}:{source_index} is {}, expected {coefficient}", matrix.coefficient(target_index, source_index));
+			}
+		}
+	}
+
+
+
+	#[test]
+	fn stereo_to_mono() {
+		let matrix:ChannelMatrix = ChannelMatrix::standard(&ChannelLayout::Stereo, &ChannelLayout::Mono);
+		assert_rows(&matrix, &[&[FRAC_1_SQRT_2, FRAC_1_SQRT_2]]);
+		assert_eq!(matrix.target_layout(), Some(&ChannelLayout::Mono));
+		assert_rows(&ChannelMatrix::by_count(2, 1), &[&[FRAC_1_SQRT_2, FRAC_1_SQRT_2]]);
+	}
+
+	#[test]
+	fn surround_51_to_stereo() {
+		let h:f32 = FRAC_1_SQRT_2;
+		assert_rows(&ChannelMatrix::standard(&ChannelLayout::Surround51, &ChannelLayout::Stereo), &[
+			&[1.0, 0.0, h, 0.0, h, 0.0],
+			&[0.0, 1.0, h, 0.0, 0.0, h]
+		]);
+	}
+
+	#[test]
+	fn surround_71_to_51() {
+		assert_rows(&ChannelMatrix::standard(&ChannelLayout::Surround71, &ChannelLayout::Surround51), &[
+			&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+			&[0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+			&[0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+			&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+			&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0],
+			&[0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0]
+		]);
+	}
+
+	#[test]
+	fn upmixes() {
+		let h:f32 = FRAC_1_SQRT_2;
+		assert_rows(&ChannelMatrix::standard(&ChannelLayout::Mono, &ChannelLayout::Stereo), &[&[h], &[h]]);
+		assert_rows(&ChannelMatrix::standard(&ChannelLayout::Stereo, &ChannelLayout::Surround51), &[&[1.0, 0.0], &[0.0, 1.0], &[0.0, 0.0], &[0.0, 0.0], &[0.0, 0.0], &[0.0, 0.0]]);
+		assert_rows(&ChannelMatrix::by_count(1, 3), &[&[1.0], &[1.0], &[1.0]]);
+		assert_rows(&ChannelMatrix::by_count(2, 3), &[&[1.0, 0.0], &[0.0, 1.0], &[1.0, 0.0]]);
+	}
+
+	#[test]
+	fn downmixes_by_count() {
+		let h:f32 = FRAC_1_SQRT_2;
+		assert_rows(&ChannelMatrix::by_count(6, 2), &[&[1.0, 0.0, h, 0.0, h, 0.0], &[0.0, 1.0, h, 0.0, 0.0, h]]);
+		assert_eq!(ChannelMatrix::by_count(6, 2).target_layout(), None);
+		assert_rows(&ChannelMatrix::by_count(5, 2), &[&[1.0 / 3.0, 0.0, 1.0 / 3.0, 0.0, 1.0 / 3.0], &[0.0, 0.5, 0.0, 0.5, 0.0]]);
+	}
+
+	#[test]
+	fn normalized_matrix() {
+		let scale:f32 = 1.0 / (1.0 + 2.0 * FRAC_1_SQRT_2);
+		let h:f32 = FRAC_1_SQRT_2 * scale;
+		assert_rows(&ChannelMatrix::standard(&ChannelLayout::Surround51, &ChannelLayout::Stereo).normalized(), &[&[scale, 0.0, h, 0.0, h, 0.0], &[0.0, scale, h, 0.0, 0.0, h]]);
+		assert_rows(&ChannelMatrix::identity(2).normalized(), &[&[1.0, 0.0], &[0.0, 1.0]]);
+	}
+
+	#[test]
+	fn custom_layouts() {
+		let source:ChannelLayout = ChannelLayout::Custom(vec![Speaker::BackCenter, Speaker::FrontCenter]);
+		assert_rows(&ChannelMatrix::standard(&source, &ChannelLayout::Quad), &[&[0.0, FRAC_1_SQRT_2], &[0.0, FRAC_1_SQRT_2], &[FRAC_1_SQRT_2, 0.0], &[FRAC_1_SQRT_2, 0.0]]);
+	}
+
+	#[test]
+	fn user_matrix() {
+		let matrix:ChannelMatrix = ChannelMatrix::new(vec![vec![0.5, 0.5, 0.0], vec![0.0, 0.0, 2.0], vec![1.0]]);
+		let buffer:AudioBuffer = AudioBuffer::new((0..10).flat_map(|_| [0.2, 0.4, 0.6]).collect(), 3, 1000).with_channel_layout(ChannelLayout::Lcr);
+		let remixed:AudioBuffer = buffer.remixed(&matrix).unwrap();
+		assert_eq!(remixed.channel_layout(), None);
+		remixed.assert_similar(&AudioBuffer::new((0..10).flat_map(|_| [0.3, 1.2, 0.2]).collect(), 3, 1000), 0.0001);
+	}
+
+	#[test]
+	fn remix_mismatch() {
+		let mut buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 10], 2, 1000);
+		assert_eq!(buffer.remix(&ChannelMatrix::identity(3)), Err(AudioBufferError::MatrixMismatch { matrix_channel_count: 3, channel_count: 2 }));
+	}
+}
@@ -0,0 +1,96 @@
+use crate::{ AudioBuffer, AudioBufferAddition, FadeCurve, analysis::is_zero_crossing, fade::{ fade_in_interleaved, fade_out_interleaved } };
+use std::time::Duration;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ConcatenationSettings {
+	crossfade:Duration,
+	crossfade_curve:FadeCurve,
+	zero_crossing_window:Option<Duration>
+}
+impl ConcatenationSettings {
+
+	/// Create settings that join buffers directly, without crossfade or alignment.
+	pub fn new() -> ConcatenationSettings {
+		ConcatenationSettings::default()
+	}
+
+	/// Return the settings with a crossfade of the given duration and curve at every join.
+	pub fn with_crossfade(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.crossfade = duration;
+		self.crossfade_curve = curve;
+		self
+	}
+
+	/// Return the settings with every join moved to zero crossings. The end of the first part and the start of the next part are trimmed to the nearest zero crossing within the given window.
+	pub fn with_zero_crossing_alignment(mut self, window:Duration) -> Self {
+		self.zero_crossing_window = Some(window);
+		self
+	}
+
+	/// Get the crossfade duration and curve.
+	pub fn crossfade(&self) -> (Duration, FadeCurve) {
+		(self.crossfade, self.crossfade_curve)
+	}
+
+	/// Get the zero crossing search window, if the joins are aligned.
+	pub fn zero_crossing_window(&self) -> Option<Duration> {
+		self.zero_crossing_window
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Join a list of buffers into one. Buffers are resampled to the settings of the first buffer. Returns None if the list is empty.
+	pub fn concatenate(buffers:Vec<AudioBuffer>, settings:&ConcatenationSettings) -> Option<AudioBuffer> {
+		let mut buffers:std::vec::IntoIter<AudioBuffer> = buffers.into_iter();
+		buffers.next().map(|first| first.appended(buffers.collect::<Vec<AudioBuffer>>(), settings))
+	}
+
+	/// Return the audio with the addition appended to the end.
+	pub fn appended<T:AudioBufferAddition>(mut self, addition:T, settings:&ConcatenationSettings) -> Self {
+		self.append(addition, settings);
+		self
+	}
+
+	/// Append the addition to the end of the audio. Resamples the addition if it doesn't match the sampling settings of self. Lists of additions are appended in order.
+	pub fn append<T:AudioBufferAddition>(&mut self, addition:T, settings:&ConcatenationSettings) {
+		for raw_addition in addition.as_raw_list(self.channel_count, self.sample_rate) {
+			self.append_raw(raw_addition, settings);
+		}
+	}
+
+	/// Append raw data that already matches the sampling settings of self.
+	fn append_raw(&mut self, mut addition:Vec<f32>, settings:&ConcatenationSettings) {
+		let channel_count:usize = self.channel_count;
+
+		// Move the join to zero crossings.
+		if let Some(window) = settings.zero_crossing_window {
+			let window_frame_count:usize = self.duration_to_frames(window);
+			let frame_count:usize = self.frame_count();
+			if let Some(end) = (frame_count.saturating_sub(window_frame_count)..frame_count).rev().find(|frame_index| is_zero_crossing(&self.data, channel_count, *frame_index)) {
+				self.replace_loop_frames(end..frame_count, 0);
+				self.data.truncate(end * channel_count);
+			}
+			let addition_frame_count:usize = addition.len() / channel_count;
+			if let Some(start) = (0..window_frame_count.min(addition_frame_count)).find(|frame_index| is_zero_crossing(&addition, channel_count, *frame_index)) {
+				addition.drain(..start * channel_count);
+			}
+		}
+
+		// Overlap the edges with a crossfade.
+		let crossfade_frame_count:usize = self.duration_to_frames(settings.crossfade).min(self.frame_count()).min(addition.len() / channel_count);
+		if crossfade_frame_count > 0 {
+			fade_out_interleaved(&mut self.data, channel_count, crossfade_frame_count, settings.crossfade_curve);
+			fade_in_interleaved(&mut addition, channel_count, crossfade_frame_count, settings.crossfade_curve);
+			let overlap_start:usize = self.data.len() - crossfade_frame_count * channel_count;
+			for (sample, addition_sample) in self.data[overlap_start..].iter_mut().zip(&addition) {
+				*sample += addition_sample;
+			}
+		}
+		self.data.extend_from_slice(&addition[crossfade_frame_count * channel_count..]);
+	}
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod test {
+	use std::{ f32::consts::FRAC_1_SQRT_2, time::Duration };
+	use crate::{ AudioBuffer, ConcatenationSettings, FadeCurve };
+
+
+
+	#[test]
+	fn concatenate_plain() {
+		let joined:AudioBuffer = AudioBuffer::concatenate(vec![
+			AudioBuffer::new(vec![0.1, 0.2], 1, 100),
+			AudioBuffer::new(vec![0.3], 1, 100),
+			AudioBuffer::new(vec![0.4, 0.5], 1, 100)
+		], &ConcatenationSettings::new()).unwrap();
+		assert_eq!(joined.data, vec![0.1, 0.2, 0.3, 0.4, 0.5]);
+		assert!(AudioBuffer::concatenate(Vec::new(), &ConcatenationSettings::new()).is_none());
+	}
+
+	#[test]
+	fn append_resamples() {
+		let joined:AudioBuffer = AudioBuffer::new(vec![0.5; 20], 2, 100).appended(AudioBuffer::new(vec![0.5; 5], 1, 50), &ConcatenationSettings::new());
+		assert_eq!(joined.frame_count(), 20);
+		joined.assert_similar(&AudioBuffer::new(vec![0.5; 40], 2, 100), 0.0001);
+	}
+
+	#[test]
+	fn append_with_crossfade() {
+		for curve in [FadeCurve::Linear, FadeCurve::SCurve] {
+			let settings:ConcatenationSettings = ConcatenationSettings::new().with_crossfade(Duration::from_millis(40), curve);
+			let joined:AudioBuffer = AudioBuffer::new(vec![0.5; 10], 1, 100).appended(AudioBuffer::new(vec![0.5; 10], 1, 100), &settings);
+			assert_eq!(joined.frame_count(), 16);
+			joined.assert_similar(&AudioBuffer::new(vec![0.5; 16], 1, 100), 0.0001);
+		}
+	}
+
+	#[test]
+	fn append_with_equal_power_crossfade() {
+		let settings:ConcatenationSettings = ConcatenationSettings::new().with_crossfade(Duration::from_millis(40), FadeCurve::EqualPower);
+		let joined:AudioBuffer = AudioBuffer::new(vec![1.0; 10], 1, 100).appended(AudioBuffer::new(vec![0.0; 10], 1, 100), &settings);
+		joined.assert_similar(&AudioBuffer::new([vec![1.0; 7], vec![0.9239, FRAC_1_SQRT_2, 0.3827], vec![0.0; 6]].concat(), 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn crossfade_is_limited_to_buffer_length() {
+		let settings:ConcatenationSettings = ConcatenationSettings::new().with_crossfade(Duration::from_secs(1), FadeCurve::Linear);
+		let joined:AudioBuffer = AudioBuffer::new(vec![0.5; 10], 1, 100).appended(vec![0.5; 4], &settings);
+		joined.assert_similar(&AudioBuffer::new(vec![0.5; 10], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn append_aligned_to_zero_crossings() {
+		let settings:ConcatenationSettings = ConcatenationSettings::new().with_zero_crossing_alignment(Duration::from_millis(50));
+		let first:AudioBuffer = AudioBuffer::new(vec![0.5, 0.2, -0.1, -0.3, -0.4, 0.2, 0.3], 1, 100);
+		let second:AudioBuffer = AudioBuffer::new(vec![-0.3, -0.2, 0.1, 0.4], 1, 100);
+		let joined:AudioBuffer = first.appended(second, &settings);
+		assert_eq!(joined.data, vec![0.5, 0.2, -0.1, -0.3, -0.4, 0.1, 0.4]);
+	}
+}
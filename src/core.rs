@@ -1,4 +1,5 @@
-use std::fmt::Debug;
+use crate::{ AudioBufferError, ChannelLayout };
+use std::{ fmt::Debug, ops::Range };
 
 
 
@@ -6,7 +7,9 @@ use std::fmt::Debug;
 pub struct AudioBuffer {
 	pub(crate) data:Vec<f32>,
 	pub(crate) channel_count:usize,
-	pub(crate) sample_rate:u32
+	pub(crate) sample_rate:u32,
+	pub(crate) channel_layout:Option<ChannelLayout>,
+	pub(crate) loop_range:Option<Range<usize>>
 }
 impl AudioBuffer {
 
@@ -17,7 +20,27 @@ impl AudioBuffer {
 		AudioBuffer {
 			data,
 			channel_count,
-			sample_rate
+			sample_rate,
+			channel_layout: None,
+			loop_range: None
+		}
+	}
+
+	/// Try to create a new audio buffer from raw data. Returns an error if the channel count or sample rate is zero, or the data does not fit the channel count.
+	pub fn try_new(data:Vec<f32>, channel_count:usize, sample_rate:u32) -> Result<AudioBuffer, AudioBufferError> {
+		let buffer:AudioBuffer = AudioBuffer::new(data, channel_count, sample_rate);
+		buffer.validate()?;
+		Ok(buffer)
+	}
+
+	/// Create a new audio buffer with the given data and the same settings as self. The loop range is not copied, as it belongs to the old data.
+	pub(crate) fn with_data(&self, data:Vec<f32>) -> AudioBuffer {
+		AudioBuffer {
+			data,
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout.clone(),
+			loop_range: None
 		}
 	}
 
@@ -47,13 +70,40 @@ impl AudioBuffer {
 
 
 
+	/* VALIDATION METHODS */
+
+	/// Check if the buffer settings are valid. Returns an error describing the first problem found.
+	pub fn validate(&self) -> Result<(), AudioBufferError> {
+		Self::validate_settings(self.channel_count, self.sample_rate)?;
+		if !self.data.len().is_multiple_of(self.channel_count) {
+			return Err(AudioBufferError::MisalignedData { sample_count: self.data.len(), channel_count: self.channel_count });
+		}
+		if let Some(layout) = &self.channel_layout && layout.channel_count() != self.channel_count {
+			return Err(AudioBufferError::LayoutMismatch { layout_channel_count: layout.channel_count(), channel_count: self.channel_count });
+		}
+		Ok(())
+	}
+
+	/// Check if the given channel count and sample rate could be used by a buffer.
+	pub(crate) fn validate_settings(channel_count:usize, sample_rate:u32) -> Result<(), AudioBufferError> {
+		if channel_count == 0 {
+			return Err(AudioBufferError::NoChannels);
+		}
+		if sample_rate == 0 {
+			return Err(AudioBufferError::NoSampleRate);
+		}
+		Ok(())
+	}
+
+
+
 	/* USAGE METHODS */
 
 	/// Print a vertical wave over time that shows the data of the buffer.
 	pub fn print_wave(&self, print_width:usize) {
 		let print_width_per_lane:f32 = print_width as f32 / self.channel_count as f32;
 		let half_print_width_per_lane:f32 = print_width_per_lane / 2.0;
-		for samples in self.data.chunks(self.channel_count) {
+		for samples in self.frames() {
 			println!(
 				"||{}||",
 				samples.iter().map(|sample| {
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferError };
+
+
+
+	#[test]
+	fn try_new_valid() {
+		let buffer:AudioBuffer = AudioBuffer::try_new(vec![0.0; 100], 2, 1000).unwrap();
+		assert_eq!(buffer, AudioBuffer::new(vec![0.0; 100], 2, 1000));
+	}
+
+	#[test]
+	fn try_new_no_channels() {
+		assert_eq!(AudioBuffer::try_new(vec![0.0; 100], 0, 1000), Err(AudioBufferError::NoChannels));
+	}
+
+	#[test]
+	fn try_new_no_sample_rate() {
+		assert_eq!(AudioBuffer::try_new(vec![0.0; 100], 2, 0), Err(AudioBufferError::NoSampleRate));
+	}
+
+	#[test]
+	fn try_new_misaligned_data() {
+		assert_eq!(AudioBuffer::try_new(vec![0.0; 100], 3, 1000), Err(AudioBufferError::MisalignedData { sample_count: 100, channel_count: 3 }));
+	}
+}
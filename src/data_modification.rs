@@ -1,12 +1,245 @@
-use crate::AudioBuffer;
-use std::ops::Range;
+use crate::{ AudioBuffer, AudioBufferAddition, AudioBufferError, OverflowStrategy, RawAddition, view::frame_range_to_sample_range };
+use std::{ ops::Range, time::Duration };
 
 
 
 impl AudioBuffer {
 	
-	/// Drain a specific amount of data and remove it from the buffer front of the. Take the channel count in consideration when passing the range. For example, a stereo sample should always take a range where the start and end are both divisible by 2.
+	/// Remove a range of raw data from the buffer and return it. Take the channel count in consideration when passing the range. For example, a stereo sample should always take a range where the start and end are both divisible by 2.
 	pub fn drain_data(&mut self, range:Range<usize>) -> Vec<f32> {
+		let channel_count:usize = self.channel_count.max(1);
+		self.replace_loop_frames(range.start / channel_count..range.end.div_ceil(channel_count), 0);
 		self.data.drain(range).collect()
 	}
-}
\ No newline at end of file
+
+
+
+	/* FRAME ADDRESSED METHODS */
+
+	/// Get a copy of a range of frames as a new buffer. The range is limited to the available frames.
+	pub fn slice_frames(&self, range:Range<usize>) -> AudioBuffer {
+		self.view_frames(range).to_buffer()
+	}
+
+	/// Remove a range of frames from the buffer and return them as a new buffer. The range is limited to the available frames.
+	pub fn drain_frames(&mut self, range:Range<usize>) -> AudioBuffer {
+		let sample_range:Range<usize> = frame_range_to_sample_range(range, self.channel_count, self.data.len());
+		let drained:Vec<f32> = self.drain_data(sample_range);
+		self.with_data(drained)
+	}
+
+	/// Split the buffer at the given frame. Self keeps everything before the frame, everything after is returned as a new buffer.
+	pub fn split_off_frames(&mut self, frame_index:usize) -> AudioBuffer {
+		let sample_index:usize = self.frame_to_sample_index(frame_index).min(self.data.len());
+		self.replace_loop_frames(frame_index.min(self.frame_count())..self.frame_count(), 0);
+		let tail:Vec<f32> = self.data.split_off(sample_index);
+		self.with_data(tail)
+	}
+
+	/// Split the buffer into two buffers at the given frame. Both buffers keep the sampling settings and channel layout.
+	pub fn split_at_frame(mut self, frame_index:usize) -> (AudioBuffer, AudioBuffer) {
+		let tail:AudioBuffer = self.split_off_frames(frame_index);
+		(self, tail)
+	}
+
+	/// Return the audio with the addition inserted at the given frame.
+	pub fn inserted_at_frame<T:AudioBufferAddition>(mut self, frame_index:usize, addition:T) -> Self {
+		self.insert_at_frame(frame_index, addition);
+		self
+	}
+
+	/// Return the audio with the addition inserted at the given frame. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_inserted_at_frame<T:AudioBufferAddition>(mut self, frame_index:usize, addition:T) -> Result<Self, AudioBufferError> {
+		self.try_insert_at_frame(frame_index, addition)?;
+		Ok(self)
+	}
+
+	/// Insert the addition at the given frame, moving everything after it back. Resamples the addition if it doesn't match the sampling settings of self. Lists of additions overlap like they do when mixing. Positions after the end insert at the end.
+	pub fn insert_at_frame<T:AudioBufferAddition>(&mut self, frame_index:usize, addition:T) {
+		let frame_index:usize = frame_index.min(self.frame_count());
+		self.replace_frames(frame_index..frame_index, addition);
+	}
+
+	/// Insert the addition at the given frame. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_insert_at_frame<T:AudioBufferAddition>(&mut self, frame_index:usize, addition:T) -> Result<(), AudioBufferError> {
+		let frame_index:usize = frame_index.min(self.frame_count());
+		self.try_replace_frames(frame_index..frame_index, addition)
+	}
+
+	/// Return the audio with the addition written over the audio from the given frame.
+	pub fn overwritten_at_frame<T:AudioBufferAddition>(mut self, frame_index:usize, addition:T) -> Self {
+		self.overwrite_at_frame(frame_index, addition);
+		self
+	}
+
+	/// Return the audio with the addition written over the audio from the given frame. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_overwritten_at_frame<T:AudioBufferAddition>(mut self, frame_index:usize, addition:T) -> Result<Self, AudioBufferError> {
+		self.try_overwrite_at_frame(frame_index, addition)?;
+		Ok(self)
+	}
+
+	/// Write the addition over the audio from the given frame. Resamples the addition if it doesn't match the sampling settings of self. Lists of additions overlap like they do when mixing. The buffer grows if the addition runs past the end, a gap between the end and the position is filled with silence. Loop points inside the overwritten frames move to its edges, like when replacing frames.
+	pub fn overwrite_at_frame<T:AudioBufferAddition>(&mut self, frame_index:usize, addition:T) {
+		let addition:Vec<f32> = self.addition_data(addition);
+		self.overwrite_data(frame_index, addition);
+	}
+
+	/// Write the addition over the audio from the given frame. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_overwrite_at_frame<T:AudioBufferAddition>(&mut self, frame_index:usize, addition:T) -> Result<(), AudioBufferError> {
+		let addition:Vec<f32> = self.try_addition_data(addition)?;
+		self.overwrite_data(frame_index, addition);
+		Ok(())
+	}
+
+	/// Return the audio with a range of frames replaced by the addition.
+	pub fn replaced_frames<T:AudioBufferAddition>(mut self, range:Range<usize>, addition:T) -> Self {
+		self.replace_frames(range, addition);
+		self
+	}
+
+	/// Return the audio with a range of frames replaced by the addition. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_replaced_frames<T:AudioBufferAddition>(mut self, range:Range<usize>, addition:T) -> Result<Self, AudioBufferError> {
+		self.try_replace_frames(range, addition)?;
+		Ok(self)
+	}
+
+	/// Replace a range of frames with the addition, which may have a different length. Resamples the addition if it doesn't match the sampling settings of self. Lists of additions overlap like they do when mixing. The range is limited to the available frames.
+	pub fn replace_frames<T:AudioBufferAddition>(&mut self, range:Range<usize>, addition:T) {
+		let addition:Vec<f32> = self.addition_data(addition);
+		self.replace_data(range, addition);
+	}
+
+	/// Replace a range of frames with the addition. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_replace_frames<T:AudioBufferAddition>(&mut self, range:Range<usize>, addition:T) -> Result<(), AudioBufferError> {
+		let addition:Vec<f32> = self.try_addition_data(addition)?;
+		self.replace_data(range, addition);
+		Ok(())
+	}
+
+	/// Return the audio padded with the given amounts of silent frames at the start and end.
+	pub fn padded_frames(mut self, start_frame_count:usize, end_frame_count:usize) -> Self {
+		self.pad_frames(start_frame_count, end_frame_count);
+		self
+	}
+
+	/// Pad the audio with the given amounts of silent frames at the start and end. The loop range moves along with the audio.
+	pub fn pad_frames(&mut self, start_frame_count:usize, end_frame_count:usize) {
+		self.replace_loop_frames(0..0, start_frame_count);
+		self.data.splice(0..0, vec![0.0; start_frame_count * self.channel_count]);
+		self.data.resize(self.data.len() + end_frame_count * self.channel_count, 0.0);
+	}
+
+
+
+	/* TIME ADDRESSED METHODS */
+
+	/// Get a copy of a time range as a new buffer. The range is limited to the duration of the buffer.
+	pub fn slice_time(&self, range:Range<Duration>) -> AudioBuffer {
+		self.slice_frames(self.time_range_to_frame_range(range))
+	}
+
+	/// Remove a time range from the buffer and return it as a new buffer. The range is limited to the duration of the buffer.
+	pub fn drain_time(&mut self, range:Range<Duration>) -> AudioBuffer {
+		let frame_range:Range<usize> = self.time_range_to_frame_range(range);
+		self.drain_frames(frame_range)
+	}
+
+	/// Split the buffer at the given time. Self keeps everything before the timestamp, everything after is returned as a new buffer.
+	pub fn split_off_time(&mut self, timestamp:Duration) -> AudioBuffer {
+		self.split_off_frames(self.duration_to_frames(timestamp))
+	}
+
+	/// Split the buffer into two buffers at the given time. Both buffers keep the sampling settings and channel layout.
+	pub fn split_at_time(self, timestamp:Duration) -> (AudioBuffer, AudioBuffer) {
+		let frame_index:usize = self.duration_to_frames(timestamp);
+		self.split_at_frame(frame_index)
+	}
+
+	/// Return the audio with the addition inserted at the given time.
+	pub fn inserted_at_time<T:AudioBufferAddition>(mut self, timestamp:Duration, addition:T) -> Self {
+		self.insert_at_time(timestamp, addition);
+		self
+	}
+
+	/// Insert the addition at the given time, moving everything after it back. Resamples the addition if it doesn't match the sampling settings of self.
+	pub fn insert_at_time<T:AudioBufferAddition>(&mut self, timestamp:Duration, addition:T) {
+		self.insert_at_frame(self.duration_to_frames(timestamp), addition);
+	}
+
+	/// Return the audio with the addition written over the audio from the given time.
+	pub fn overwritten_at_time<T:AudioBufferAddition>(mut self, timestamp:Duration, addition:T) -> Self {
+		self.overwrite_at_time(timestamp, addition);
+		self
+	}
+
+	/// Write the addition over the audio from the given time. Resamples the addition if it doesn't match the sampling settings of self. The buffer grows if the addition runs past the end.
+	pub fn overwrite_at_time<T:AudioBufferAddition>(&mut self, timestamp:Duration, addition:T) {
+		self.overwrite_at_frame(self.duration_to_frames(timestamp), addition);
+	}
+
+	/// Return the audio with a time range replaced by the addition.
+	pub fn replaced_time<T:AudioBufferAddition>(mut self, range:Range<Duration>, addition:T) -> Self {
+		self.replace_time(range, addition);
+		self
+	}
+
+	/// Replace a time range with the addition, which may have a different length. Resamples the addition if it doesn't match the sampling settings of self. The range is limited to the duration of the buffer.
+	pub fn replace_time<T:AudioBufferAddition>(&mut self, range:Range<Duration>, addition:T) {
+		self.replace_frames(self.time_range_to_frame_range(range), addition);
+	}
+
+	/// Return the audio padded with silence of the given durations at the start and end.
+	pub fn padded(mut self, start:Duration, end:Duration) -> Self {
+		self.pad(start, end);
+		self
+	}
+
+	/// Pad the audio with silence of the given durations at the start and end.
+	pub fn pad(&mut self, start:Duration, end:Duration) {
+		self.pad_frames(self.duration_to_frames(start), self.duration_to_frames(end));
+	}
+
+	/// Convert a time range to a range of frames.
+	pub(crate) fn time_range_to_frame_range(&self, range:Range<Duration>) -> Range<usize> {
+		self.duration_to_frames(range.start)..self.duration_to_frames(range.end)
+	}
+
+	/// Convert an addition to a single list of data matching the sampling settings of self. Lists of additions overlap like they do when mixing. A trailing partial frame is dropped to keep the data aligned.
+	fn addition_data<T:AudioBufferAddition>(&self, addition:T) -> Vec<f32> {
+		let mut mixed:AudioBuffer = self.with_data(Vec::new());
+		mixed.combine_raw(addition.into_placed_list(self.channel_count, self.sample_rate), OverflowStrategy::None);
+		let aligned_sample_count:usize = mixed.data.len() / self.channel_count * self.channel_count;
+		mixed.data.truncate(aligned_sample_count);
+		mixed.data
+	}
+
+	/// Convert an addition to a single list of data matching the sampling settings of self. Returns an error if self is invalid or any part of the addition does not contain whole frames.
+	fn try_addition_data<T:AudioBufferAddition>(&self, addition:T) -> Result<Vec<f32>, AudioBufferError> {
+		self.validate()?;
+		let raw_addition:Vec<RawAddition> = addition.try_into_placed_list(self.channel_count, self.sample_rate)?;
+		if let Some(misaligned) = raw_addition.iter().find(|addition| !addition.data.len().is_multiple_of(self.channel_count)) {
+			return Err(AudioBufferError::MisalignedData { sample_count: misaligned.data.len(), channel_count: self.channel_count });
+		}
+		Ok(self.addition_data(raw_addition))
+	}
+
+	/// Write aligned data over the audio from the given frame, growing the buffer if needed.
+	fn overwrite_data(&mut self, frame_index:usize, data:Vec<f32>) {
+		let frame_count:usize = data.len() / self.channel_count;
+		let start:usize = frame_index * self.channel_count;
+		let end:usize = start + data.len();
+		let overwritten:Range<usize> = frame_index.min(self.frame_count())..(frame_index + frame_count).min(self.frame_count());
+		self.replace_loop_frames(overwritten.clone(), overwritten.len());
+		if end > self.data.len() {
+			self.data.resize(end, 0.0);
+		}
+		self.data[start..end].copy_from_slice(&data);
+	}
+
+	/// Replace a range of frames with aligned data.
+	fn replace_data(&mut self, range:Range<usize>, data:Vec<f32>) {
+		let sample_range:Range<usize> = frame_range_to_sample_range(range, self.channel_count, self.data.len());
+		self.replace_loop_frames(sample_range.start / self.channel_count..sample_range.end / self.channel_count, data.len() / self.channel_count);
+		self.data.splice(sample_range, data);
+	}
+}
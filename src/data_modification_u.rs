@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
-	use crate::AudioBuffer;
+	use crate::{ AudioBuffer, AudioBufferError, ChannelLayout };
+	use std::time::Duration;
 
 
 
@@ -15,4 +16,129 @@ mod test {
 		assert_eq!(buffer.drain_data(10..20), (0..10).map(|index| (20 + index) as f32).collect::<Vec<f32>>());
 		assert_eq!(buffer.data.len(), 80);
 	}
-}
\ No newline at end of file
+
+
+
+	fn stereo_counting_buffer() -> AudioBuffer {
+		AudioBuffer::new((0..200).map(|index| index as f32).collect(), 2, 1000)
+	}
+
+	#[test]
+	fn slice_frames() {
+		let buffer:AudioBuffer = stereo_counting_buffer();
+		assert_eq!(buffer.slice_frames(10..20), AudioBuffer::new((20..40).map(|index| index as f32).collect(), 2, 1000));
+		assert_eq!(buffer.slice_frames(90..200), AudioBuffer::new((180..200).map(|index| index as f32).collect(), 2, 1000));
+		assert_eq!(buffer.slice_frames(150..200).data.len(), 0);
+		assert_eq!(buffer.data.len(), 200);
+	}
+
+	#[test]
+	fn drain_frames() {
+		let mut buffer:AudioBuffer = stereo_counting_buffer();
+		assert_eq!(buffer.drain_frames(0..10), AudioBuffer::new((0..20).map(|index| index as f32).collect(), 2, 1000));
+		assert_eq!(buffer.frame_count(), 90);
+		assert_eq!(buffer.data[0], 20.0);
+	}
+
+	#[test]
+	fn split_off_frames() {
+		let mut buffer:AudioBuffer = stereo_counting_buffer();
+		let tail:AudioBuffer = buffer.split_off_frames(40);
+		assert_eq!(buffer, AudioBuffer::new((0..80).map(|index| index as f32).collect(), 2, 1000));
+		assert_eq!(tail, AudioBuffer::new((80..200).map(|index| index as f32).collect(), 2, 1000));
+	}
+
+	#[test]
+	fn time_addressed_modification() {
+		let mut buffer:AudioBuffer = stereo_counting_buffer();
+		assert_eq!(buffer.slice_time(Duration::from_millis(10)..Duration::from_millis(20)), buffer.slice_frames(10..20));
+
+		let drained:AudioBuffer = buffer.drain_time(Duration::from_millis(0)..Duration::from_millis(50));
+		assert_eq!(drained.duration(), Duration::from_millis(50));
+		assert_eq!(buffer.duration(), Duration::from_millis(50));
+
+		let tail:AudioBuffer = buffer.split_off_time(Duration::from_millis(20));
+		assert_eq!(buffer.duration(), Duration::from_millis(20));
+		assert_eq!(tail.duration(), Duration::from_millis(30));
+		assert_eq!(tail.data[0], 140.0);
+	}
+
+	#[test]
+	fn split_at_frame() {
+		let buffer:AudioBuffer = stereo_counting_buffer().with_channel_layout(ChannelLayout::Stereo);
+		let (head, tail) = buffer.clone().split_at_frame(30);
+		assert_eq!(head, buffer.slice_frames(0..30));
+		assert_eq!(tail, buffer.slice_frames(30..100));
+		assert_eq!(tail.channel_layout(), Some(&ChannelLayout::Stereo));
+
+		let (head, tail) = buffer.clone().split_at_time(Duration::from_millis(150));
+		assert_eq!(head, buffer);
+		assert_eq!(tail.frame_count(), 0);
+	}
+
+	#[test]
+	fn insert_at_frame() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![1.0, 2.0, 3.0], 1, 1000);
+		assert_eq!(buffer.clone().inserted_at_frame(1, vec![8.0, 9.0]).data, vec![1.0, 8.0, 9.0, 2.0, 3.0]);
+		assert_eq!(buffer.clone().inserted_at_frame(10, vec![8.0]).data, vec![1.0, 2.0, 3.0, 8.0]);
+		assert_eq!(buffer.inserted_at_time(Duration::ZERO, vec![vec![7.0, 1.0], vec![0.5]]).data, vec![7.5, 1.0, 1.0, 2.0, 3.0]);
+	}
+
+	#[test]
+	fn insert_resamples_addition() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 8], 2, 1000).with_channel_layout(ChannelLayout::Stereo);
+		let inserted:AudioBuffer = buffer.inserted_at_time(Duration::from_millis(2), AudioBuffer::new(vec![0.5; 4], 1, 500));
+		assert_eq!(inserted.sample_rate(), 1000);
+		assert_eq!(inserted.channel_layout(), Some(&ChannelLayout::Stereo));
+		inserted.assert_similar(&AudioBuffer::new([vec![0.0; 4], vec![0.5; 16], vec![0.0; 4]].concat(), 2, 1000), 0.0001);
+	}
+
+	#[test]
+	fn overwrite_at_frame() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![1.0, 2.0, 3.0, 4.0], 1, 1000);
+		assert_eq!(buffer.clone().overwritten_at_frame(1, vec![8.0, 9.0]).data, vec![1.0, 8.0, 9.0, 4.0]);
+		assert_eq!(buffer.clone().overwritten_at_frame(3, vec![8.0, 9.0]).data, vec![1.0, 2.0, 3.0, 8.0, 9.0]);
+		assert_eq!(buffer.overwritten_at_time(Duration::from_millis(6), vec![8.0]).data, vec![1.0, 2.0, 3.0, 4.0, 0.0, 0.0, 8.0]);
+	}
+
+	#[test]
+	fn overwrite_moves_loop() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 10], 1, 1000).with_loop_range(2..6);
+		assert_eq!(buffer.clone().overwritten_at_frame(6, vec![1.0; 8]).loop_range(), Some(2..6));
+		assert_eq!(buffer.clone().overwritten_at_frame(4, vec![1.0; 4]).loop_range(), Some(2..4));
+		assert_eq!(buffer.overwritten_at_frame(0, vec![1.0; 4]).loop_range(), Some(4..6));
+	}
+
+	#[test]
+	fn try_modify_misaligned() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 4], 2, 1000);
+		let error:AudioBufferError = AudioBufferError::MisalignedData { sample_count: 3, channel_count: 2 };
+		assert_eq!(buffer.clone().try_inserted_at_frame(1, vec![1.0; 3]), Err(error.clone()));
+		assert_eq!(buffer.clone().try_overwritten_at_frame(1, vec![vec![1.0; 2], vec![1.0; 3]]), Err(error.clone()));
+		assert_eq!(buffer.clone().try_replaced_frames(0..1, vec![1.0; 3]), Err(error));
+		assert_eq!(buffer.clone().try_replaced_frames(0..1, vec![1.0; 4]).unwrap().data, vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0]);
+		assert_eq!(buffer.inserted_at_frame(1, vec![1.0; 3]).data, vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+	}
+
+	#[test]
+	fn replace_frames() {
+		let buffer:AudioBuffer = stereo_counting_buffer();
+		let replaced:AudioBuffer = buffer.clone().replaced_frames(10..90, vec![-1.0; 4]);
+		assert_eq!(replaced.frame_count(), 22);
+		assert_eq!(replaced.slice_frames(10..12).data, vec![-1.0; 4]);
+		assert_eq!(replaced.slice_frames(12..22), buffer.slice_frames(90..100));
+
+		let replaced:AudioBuffer = buffer.clone().replaced_time(Duration::from_millis(50)..Duration::from_secs(1), vec![-1.0; 2]);
+		assert_eq!(replaced.frame_count(), 51);
+		assert_eq!(replaced.data[100..], [-1.0, -1.0]);
+	}
+
+	#[test]
+	fn pad() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![1.0, 2.0], 2, 1000);
+		assert_eq!(buffer.clone().padded_frames(1, 2).data, vec![0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+		let padded:AudioBuffer = buffer.padded(Duration::from_millis(2), Duration::ZERO);
+		assert_eq!(padded.data, vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0]);
+		assert_eq!(padded.duration(), Duration::from_millis(3));
+	}
+}
@@ -0,0 +1,349 @@
+use crate::{ AudioBuffer, AudioBufferError, AudioEffect, FadeCurve, analysis::decibels_to_gain };
+use std::{ fmt::{ self, Display, Formatter }, ops::Range, time::Duration };
+
+
+
+type EffectFactory = Box<dyn Fn() -> Box<dyn AudioEffect>>;
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum EditOperation {
+	/// Keep only the given time range.
+	Trim(Range<Duration>),
+
+	/// Change the gain of the whole audio by the given amount of decibels.
+	Gain(f32),
+
+	/// Fade in from silence over the given duration.
+	FadeIn(Duration, FadeCurve),
+
+	/// Fade out to silence over the given duration at the end.
+	FadeOut(Duration, FadeCurve),
+
+	/// Remove the given time range and keep it on the clipboard.
+	Cut(Range<Duration>),
+
+	/// Keep a copy of the given time range on the clipboard.
+	Copy(Range<Duration>),
+
+	/// Insert the clipboard at the given time. Does nothing if the clipboard is empty.
+	Paste(Duration),
+
+	/// Apply a registered effect to the given time range, or to the whole audio if there is no range.
+	ApplyEffect { name:String, range:Option<Range<Duration>> }
+}
+impl EditOperation {
+
+	/// Parse an operation from a single line of an edit list.
+	fn parse(line:&str) -> Option<EditOperation> {
+		let words:Vec<&str> = line.split_whitespace().collect();
+		let range = |start:&str, end:&str| Some(parse_time(start)?..parse_time(end)?);
+		match words.as_slice() {
+			["trim", start, end] => range(start, end).map(EditOperation::Trim),
+			["gain", decibels] => decibels.parse().ok().map(EditOperation::Gain),
+			["fade_in", duration, curve] => Some(EditOperation::FadeIn(parse_time(duration)?, parse_curve(curve)?)),
+			["fade_out", duration, curve] => Some(EditOperation::FadeOut(parse_time(duration)?, parse_curve(curve)?)),
+			["cut", start, end] => range(start, end).map(EditOperation::Cut),
+			["copy", start, end] => range(start, end).map(EditOperation::Copy),
+			["paste", position] => parse_time(position).map(EditOperation::Paste),
+			["effect", name] => Some(EditOperation::ApplyEffect { name: name.to_string(), range: None }),
+			["effect", name, start, end] => Some(EditOperation::ApplyEffect { name: name.to_string(), range: Some(range(start, end)?) }),
+			_ => None
+		}
+	}
+}
+impl Display for EditOperation {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		match self {
+			EditOperation::Trim(range) => write!(f, "trim {} {}", format_time(range.start), format_time(range.end)),
+			EditOperation::Gain(decibels) => write!(f, "gain {decibels}"),
+			EditOperation::FadeIn(duration, curve) => write!(f, "fade_in {} {}", format_time(*duration), curve_name(curve)),
+			EditOperation::FadeOut(duration, curve) => write!(f, "fade_out {} {}", format_time(*duration), curve_name(curve)),
+			EditOperation::Cut(range) => write!(f, "cut {} {}", format_time(range.start), format_time(range.end)),
+			EditOperation::Copy(range) => write!(f, "copy {} {}", format_time(range.start), format_time(range.end)),
+			EditOperation::Paste(position) => write!(f, "paste {}", format_time(*position)),
+			EditOperation::ApplyEffect { name, range: None } => write!(f, "effect {name}"),
+			EditOperation::ApplyEffect { name, range: Some(range) } => write!(f, "effect {name} {} {}", format_time(range.start), format_time(range.end))
+		}
+	}
+}
+
+
+
+struct RenderState {
+	operation_count:usize,
+	buffer:AudioBuffer,
+	clipboard:Option<AudioBuffer>
+}
+
+
+
+pub struct EditSession {
+	source:AudioBuffer,
+	operations:Vec<EditOperation>,
+	undone_operations:Vec<EditOperation>,
+	effects:Vec<(String, EffectFactory)>,
+	rendered:Option<RenderState>
+}
+impl EditSession {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new edit session on the given source. The source is never modified, edits are recorded and rendered on request.
+	pub fn new(source:AudioBuffer) -> EditSession {
+		EditSession {
+			source,
+			operations: Vec::new(),
+			undone_operations: Vec::new(),
+			effects: Vec::new(),
+			rendered: None
+		}
+	}
+
+	/// Register an effect that can be applied by name. The factory creates a fresh effect every time the edit list is rendered, so stateful effects replay identically. Returns an error if the name is empty or contains whitespace, as it could not be stored in an edit list.
+	pub fn register_effect<T:Fn() -> Box<dyn AudioEffect> + 'static>(&mut self, name:&str, factory:T) -> Result<(), AudioBufferError> {
+		validate_effect_name(name)?;
+		self.effects.retain(|(effect_name, _)| effect_name != name);
+		self.effects.push((name.to_string(), Box::new(factory)));
+		self.rendered = None;
+		Ok(())
+	}
+
+
+
+	/* PROPERTY METHODS */
+
+	/// Get the source audio.
+	pub fn source(&self) -> &AudioBuffer {
+		&self.source
+	}
+
+	/// Replace the source audio, keeping the edit list. All operations are addressed in time, so the edits can be replayed on a source with a different sample rate.
+	pub fn set_source(&mut self, source:AudioBuffer) {
+		self.source = source;
+		self.rendered = None;
+	}
+
+	/// Get the recorded operations in order.
+	pub fn operations(&self) -> &[EditOperation] {
+		&self.operations
+	}
+
+
+
+	/* EDITING METHODS */
+
+	/// Record an operation. Clears the operations that could be redone. Returns an error if an effect name is empty or contains whitespace, as it could not be stored in an edit list.
+	pub fn push(&mut self, operation:EditOperation) -> Result<(), AudioBufferError> {
+		if let EditOperation::ApplyEffect { name, .. } = &operation {
+			validate_effect_name(name)?;
+		}
+		self.record(operation);
+		Ok(())
+	}
+
+	/// Record an operation that is known to be valid. Clears the operations that could be redone.
+	fn record(&mut self, operation:EditOperation) {
+		self.operations.push(operation);
+		self.undone_operations.clear();
+	}
+
+	/// Record keeping only the given time range.
+	pub fn trim(&mut self, range:Range<Duration>) {
+		self.record(EditOperation::Trim(range));
+	}
+
+	/// Record a gain change in decibels.
+	pub fn gain(&mut self, decibels:f32) {
+		self.record(EditOperation::Gain(decibels));
+	}
+
+	/// Record a fade-in.
+	pub fn fade_in(&mut self, duration:Duration, curve:FadeCurve) {
+		self.record(EditOperation::FadeIn(duration, curve));
+	}
+
+	/// Record a fade-out.
+	pub fn fade_out(&mut self, duration:Duration, curve:FadeCurve) {
+		self.record(EditOperation::FadeOut(duration, curve));
+	}
+
+	/// Record cutting a time range to the clipboard.
+	pub fn cut(&mut self, range:Range<Duration>) {
+		self.record(EditOperation::Cut(range));
+	}
+
+	/// Record copying a time range to the clipboard.
+	pub fn copy(&mut self, range:Range<Duration>) {
+		self.record(EditOperation::Copy(range));
+	}
+
+	/// Record pasting the clipboard at the given time.
+	pub fn paste(&mut self, position:Duration) {
+		self.record(EditOperation::Paste(position));
+	}
+
+	/// Record applying a registered effect to a time range, or to the whole audio if there is no range. Returns an error if the name is empty or contains whitespace.
+	pub fn apply_effect(&mut self, name:&str, range:Option<Range<Duration>>) -> Result<(), AudioBufferError> {
+		self.push(EditOperation::ApplyEffect { name: name.to_string(), range })
+	}
+
+	/// Undo the last operation. Returns false if there was nothing to undo.
+	pub fn undo(&mut self) -> bool {
+		match self.operations.pop() {
+			Some(operation) => {
+				self.undone_operations.push(operation);
+				if self.rendered.as_ref().is_some_and(|state| state.operation_count > self.operations.len()) {
+					self.rendered = None;
+				}
+				true
+			},
+			None => false
+		}
+	}
+
+	/// Redo the last undone operation. Returns false if there was nothing to redo.
+	pub fn redo(&mut self) -> bool {
+		match self.undone_operations.pop() {
+			Some(operation) => {
+				self.operations.push(operation);
+				true
+			},
+			None => false
+		}
+	}
+
+	/// Wether or not there is an operation to undo.
+	pub fn can_undo(&self) -> bool {
+		!self.operations.is_empty()
+	}
+
+	/// Wether or not there is an operation to redo.
+	pub fn can_redo(&self) -> bool {
+		!self.undone_operations.is_empty()
+	}
+
+
+
+	/* SERIALIZATION METHODS */
+
+	/// Get the edit list as text, one operation per line. Times are stored in seconds.
+	pub fn edit_list(&self) -> String {
+		self.operations.iter().map(|operation| format!("{operation}\n")).collect()
+	}
+
+	/// Replace the recorded operations with the given edit list. Empty lines and lines starting with '#' are ignored. Returns an error for the first line that is not a valid operation, without changing the session.
+	pub fn set_edit_list(&mut self, edit_list:&str) -> Result<(), AudioBufferError> {
+		let mut operations:Vec<EditOperation> = Vec::new();
+		for (line_index, line) in edit_list.lines().enumerate() {
+			let line:&str = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			operations.push(EditOperation::parse(line).ok_or(AudioBufferError::InvalidEditList { line_index })?);
+		}
+		self.operations = operations;
+		self.undone_operations.clear();
+		self.rendered = None;
+		Ok(())
+	}
+
+
+
+	/* RENDERING METHODS */
+
+	/// Render the edits onto the source. Only operations added since the last render are processed. Returns an error if an operation uses an effect that is not registered. The operations before it stay rendered.
+	pub fn render(&mut self) -> Result<&AudioBuffer, AudioBufferError> {
+		let mut state:RenderState = self.rendered.take().unwrap_or_else(|| RenderState { operation_count: 0, buffer: self.source.clone(), clipboard: None });
+		for operation in &self.operations[state.operation_count..] {
+			if let Err(error) = self.render_operation(operation, &mut state) {
+				self.rendered = Some(state);
+				return Err(error);
+			}
+			state.operation_count += 1;
+		}
+		Ok(&self.rendered.insert(state).buffer)
+	}
+
+	/// Apply a single operation to the render state.
+	fn render_operation(&self, operation:&EditOperation, state:&mut RenderState) -> Result<(), AudioBufferError> {
+		let buffer:&mut AudioBuffer = &mut state.buffer;
+		match operation {
+			EditOperation::Trim(range) => *buffer = slice_time_with_loop(buffer, range.clone()),
+			EditOperation::Gain(decibels) => {
+				let gain:f32 = decibels_to_gain(*decibels);
+				buffer.data.iter_mut().for_each(|sample| *sample *= gain);
+			},
+			EditOperation::FadeIn(duration, curve) => buffer.fade_in(*duration, *curve),
+			EditOperation::FadeOut(duration, curve) => buffer.fade_out(*duration, *curve),
+			EditOperation::Cut(range) => state.clipboard = Some(buffer.drain_time(range.clone())),
+			EditOperation::Copy(range) => state.clipboard = Some(slice_time_with_loop(buffer, range.clone())),
+			EditOperation::Paste(position) => if let Some(clipboard) = &state.clipboard {
+				buffer.insert_at_time(*position, clipboard.clone());
+			},
+			EditOperation::ApplyEffect { name, range } => {
+				let factory = self.effects.iter().find(|(effect_name, _)| effect_name == name).map(|(_, factory)| factory).ok_or(AudioBufferError::UnknownEffect { name: name.clone() })?;
+				let frame_range:Range<usize> = match range {
+					Some(range) => buffer.time_range_to_frame_range(range.clone()),
+					None => 0..buffer.frame_count()
+				};
+				factory().apply_to_view(&mut buffer.view_frames_mut(frame_range));
+			}
+		}
+		Ok(())
+	}
+}
+
+
+
+/// Copy a time range of the buffer, keeping the part of the loop range within it.
+fn slice_time_with_loop(buffer:&AudioBuffer, range:Range<Duration>) -> AudioBuffer {
+	let frame_range:Range<usize> = buffer.time_range_to_frame_range(range);
+	let end:usize = frame_range.end.min(buffer.frame_count());
+	let start:usize = frame_range.start.min(end);
+	let mut slice:AudioBuffer = buffer.slice_frames(start..end);
+	slice.loop_range = buffer.loop_range.clone();
+	slice.replace_loop_frames(end..buffer.frame_count(), 0);
+	slice.replace_loop_frames(0..start, 0);
+	slice
+}
+
+/// Format a duration as seconds with nanosecond precision.
+fn format_time(time:Duration) -> String {
+	format!("{}.{:09}", time.as_secs(), time.subsec_nanos())
+}
+
+/// Parse a duration from seconds with up to nanosecond precision.
+fn parse_time(text:&str) -> Option<Duration> {
+	let (seconds, fraction) = text.split_once('.').unwrap_or((text, ""));
+	if fraction.len() > 9 || !fraction.chars().all(|character| character.is_ascii_digit()) {
+		return None;
+	}
+	let nanos:u32 = format!("{fraction:0<9}").parse().ok()?;
+	Some(Duration::new(seconds.parse().ok()?, nanos))
+}
+
+/// Get the name of a fade curve in an edit list.
+fn curve_name(curve:&FadeCurve) -> &'static str {
+	match curve {
+		FadeCurve::Linear => "linear",
+		FadeCurve::EqualPower => "equal_power",
+		FadeCurve::SCurve => "s_curve",
+		FadeCurve::Exponential => "exponential",
+		FadeCurve::Logarithmic => "logarithmic"
+	}
+}
+
+/// Parse a fade curve from its name in an edit list.
+fn parse_curve(name:&str) -> Option<FadeCurve> {
+	[FadeCurve::Linear, FadeCurve::EqualPower, FadeCurve::SCurve, FadeCurve::Exponential, FadeCurve::Logarithmic].into_iter().find(|curve| curve_name(curve) == name)
+}
+
+/// Check that an effect name can be stored as a single word in an edit list.
+fn validate_effect_name(name:&str) -> Result<(), AudioBufferError> {
+	if name.is_empty() || name.chars().any(char::is_whitespace) {
+		return Err(AudioBufferError::InvalidEffectName { name: name.to_string() });
+	}
+	Ok(())
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioAmplifier, AudioBuffer, AudioBufferError, EditOperation, EditSession, FadeCurve };
+	use std::time::Duration;
+
+
+
+	fn counting_buffer(frame_count:usize, sample_rate:u32) -> AudioBuffer {
+		AudioBuffer::new((0..frame_count).map(|index| index as f32).collect(), 1, sample_rate)
+	}
+
+	#[test]
+	fn render_keeps_source() {
+		let mut session:EditSession = EditSession::new(counting_buffer(10, 1000));
+		session.trim(Duration::from_millis(2)..Duration::from_millis(8));
+		session.gain(20.0);
+		session.render().unwrap().assert_similar(&AudioBuffer::new((2..8).map(|index| index as f32 * 10.0).collect(), 1, 1000), 0.0001);
+		assert_eq!(session.source(), &counting_buffer(10, 1000));
+	}
+
+	#[test]
+	fn cut_copy_and_paste() {
+		let mut session:EditSession = EditSession::new(counting_buffer(6, 1000));
+		session.cut(Duration::ZERO..Duration::from_millis(2));
+		session.paste(Duration::from_millis(4));
+		assert_eq!(session.render().unwrap().data, vec![2.0, 3.0, 4.0, 5.0, 0.0, 1.0]);
+
+		session.copy(Duration::from_millis(5)..Duration::from_millis(6));
+		session.paste(Duration::ZERO);
+		assert_eq!(session.render().unwrap().data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 0.0, 1.0]);
+	}
+
+	#[test]
+	fn fades_and_effects() {
+		let mut session:EditSession = EditSession::new(AudioBuffer::new(vec![1.0; 6], 1, 1000));
+		session.register_effect("half", || Box::new(AudioAmplifier::new(0.5))).unwrap();
+		session.fade_in(Duration::from_millis(2), FadeCurve::Linear);
+		session.fade_out(Duration::from_millis(2), FadeCurve::Linear);
+		session.apply_effect("half", Some(Duration::from_millis(2)..Duration::from_millis(3))).unwrap();
+		assert_eq!(session.render().unwrap().data, vec![0.0, 0.5, 0.5, 1.0, 1.0, 0.0]);
+
+		session.apply_effect("missing", None).unwrap();
+		session.gain(6.0206);
+		assert_eq!(session.render().err(), Some(AudioBufferError::UnknownEffect { name: "missing".to_string() }));
+		session.register_effect("missing", || Box::new(AudioAmplifier::new(4.0))).unwrap();
+		session.render().unwrap().assert_similar(&AudioBuffer::new(vec![0.0, 4.0, 4.0, 8.0, 8.0, 0.0], 1, 1000), 0.001);
+	}
+
+	#[test]
+	fn undo_and_redo() {
+		let mut session:EditSession = EditSession::new(counting_buffer(4, 1000));
+		assert!(!session.can_undo() && !session.undo());
+		session.gain(6.0);
+		session.trim(Duration::ZERO..Duration::from_millis(2));
+		assert_eq!(session.render().unwrap().frame_count(), 2);
+
+		assert!(session.undo());
+		assert!(session.can_redo());
+		assert_eq!(session.render().unwrap().frame_count(), 4);
+		assert!(session.undo());
+		assert_eq!(session.render(), Ok(&counting_buffer(4, 1000)));
+
+		assert!(session.redo());
+		assert_eq!(session.operations(), &[EditOperation::Gain(6.0)]);
+		session.fade_in(Duration::from_millis(1), FadeCurve::Linear);
+		assert!(!session.can_redo() && !session.redo());
+		assert_eq!(session.render().unwrap().data[0], 0.0);
+	}
+
+	#[test]
+	fn edit_list_round_trip() {
+		let mut session:EditSession = EditSession::new(counting_buffer(10, 1000));
+		session.trim(Duration::from_millis(1)..Duration::from_nanos(8_000_000_001));
+		session.gain(-3.5);
+		session.fade_in(Duration::from_millis(250), FadeCurve::SCurve);
+		session.fade_out(Duration::from_secs(1), FadeCurve::Logarithmic);
+		session.cut(Duration::ZERO..Duration::from_millis(1));
+		session.copy(Duration::ZERO..Duration::from_millis(1));
+		session.paste(Duration::from_millis(3));
+		session.apply_effect("reverb", None).unwrap();
+		session.apply_effect("reverb", Some(Duration::ZERO..Duration::from_secs(2))).unwrap();
+		let edit_list:String = session.edit_list();
+		assert!(edit_list.starts_with("trim 0.001000000 8.000000001\ngain -3.5\nfade_in 0.250000000 s_curve\n"));
+
+		let mut replayed:EditSession = EditSession::new(counting_buffer(10, 1000));
+		replayed.set_edit_list(&format!("# Edits\n\n{edit_list}")).unwrap();
+		assert_eq!(replayed.operations(), session.operations());
+		assert_eq!(replayed.set_edit_list("gain 1\nfade_in 1 unknown"), Err(AudioBufferError::InvalidEditList { line_index: 1 }));
+		assert_eq!(replayed.operations(), session.operations());
+	}
+
+	#[test]
+	fn effect_names_without_whitespace() {
+		let mut session:EditSession = EditSession::new(counting_buffer(4, 1000));
+		assert_eq!(session.register_effect("big hall", || Box::new(AudioAmplifier::new(0.5))), Err(AudioBufferError::InvalidEffectName { name: "big hall".to_string() }));
+		assert_eq!(session.apply_effect("big hall", None), Err(AudioBufferError::InvalidEffectName { name: "big hall".to_string() }));
+		assert_eq!(session.apply_effect("", None), Err(AudioBufferError::InvalidEffectName { name: String::new() }));
+		assert_eq!(session.push(EditOperation::ApplyEffect { name: "big hall".to_string(), range: None }), Err(AudioBufferError::InvalidEffectName { name: "big hall".to_string() }));
+		assert!(session.operations().is_empty());
+		assert_eq!(session.render().map(|rendered| rendered.frame_count()), Ok(4));
+	}
+
+	#[test]
+	fn trim_and_copy_keep_loop() {
+		let mut session:EditSession = EditSession::new(counting_buffer(10, 1000).with_loop_range(4..8));
+		session.trim(Duration::from_millis(2)..Duration::from_millis(6));
+		assert_eq!(session.render().unwrap().loop_range(), Some(2..4));
+		session.copy(Duration::from_millis(1)..Duration::from_millis(3));
+		session.paste(Duration::ZERO);
+		assert_eq!(session.render().unwrap().loop_range(), Some(4..6));
+	}
+
+	#[test]
+	fn replay_on_higher_resolution_source() {
+		let mut session:EditSession = EditSession::new(counting_buffer(10, 1000));
+		session.set_edit_list("cut 0.002 0.004\npaste 0.006").unwrap();
+		assert_eq!(session.render().unwrap().data, vec![0.0, 1.0, 4.0, 5.0, 6.0, 7.0, 2.0, 3.0, 8.0, 9.0]);
+
+		session.set_source(counting_buffer(20, 2000));
+		assert_eq!(session.render().unwrap().data, [vec![0.0, 1.0, 2.0, 3.0], (8..16).map(|index| index as f32).collect(), vec![4.0, 5.0, 6.0, 7.0], (16..20).map(|index| index as f32).collect()].concat());
+	}
+}
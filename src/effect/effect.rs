@@ -1,4 +1,4 @@
-use crate::{ AudioBuffer, AudioSettings };
+use crate::{ AudioBuffer, AudioBufferMut, AudioSettings };
 
 
 
@@ -7,9 +7,20 @@ pub trait AudioEffect {
 	/// Apply the effect to an audio buffer.
 	fn apply_to(&mut self, buffer:&mut AudioBuffer);
 
+	/// Apply the effect to a mutable view of audio data. By default the view is copied into a temporary buffer for `apply_to` and copied back. Effects that can process views in place should implement this method and call it from `apply_to`. Samples the effect adds beyond the length of the view are dropped.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
+		let mut temporary_buffer:AudioBuffer = buffer.to_buffer();
+		self.apply_to(&mut temporary_buffer);
+		let sample_count:usize = buffer.data.len().min(temporary_buffer.data.len());
+		buffer.data[..sample_count].copy_from_slice(&temporary_buffer.data[..sample_count]);
+	}
+
+	/// Clear the state kept between calls, so the next audio is processed as if it were the first. Stateless effects keep the default, which does nothing.
+	fn reset(&mut self) {}
+
 	/// Get the settings.
 	fn settings(&self) -> &AudioSettings;
 
 	/// Get the settings mutably.
 	fn settings_mut(&mut self) -> &mut AudioSettings;
-}
\ No newline at end of file
+}
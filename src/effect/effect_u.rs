@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioEffect, AudioSettings };
+
+
+
+	struct BufferOnlyEffect {
+		settings:AudioSettings
+	}
+	impl AudioEffect for BufferOnlyEffect {
+		fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+			buffer.data_mut().iter_mut().for_each(|sample| *sample *= 2.0);
+		}
+
+		fn settings(&self) -> &AudioSettings {
+			&self.settings
+		}
+
+		fn settings_mut(&mut self) -> &mut AudioSettings {
+			&mut self.settings
+		}
+	}
+
+	#[test]
+	fn buffer_only_effect_applies_to_view() {
+		let mut buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 8], 2, 100);
+		let mut effect:BufferOnlyEffect = BufferOnlyEffect { settings: AudioSettings::default() };
+		effect.apply_to_view(&mut buffer.view_frames_mut(1..3));
+		assert_eq!(buffer.data(), &[1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 1.0, 1.0]);
+
+		effect.apply_to(&mut buffer);
+		assert_eq!(buffer.data(), &[2.0, 2.0, 4.0, 4.0, 4.0, 4.0, 2.0, 2.0]);
+	}
+}
@@ -1,4 +1,4 @@
-use crate::{ AudioBuffer, AudioEffect, AudioSettings };
+use crate::{ AudioBuffer, AudioBufferMut, AudioEffect, AudioSettings };
 
 
 
@@ -35,9 +35,13 @@ impl AudioAmplifier {
 }
 impl AudioEffect for AudioAmplifier {
 
-
 	/// Apply the effect to an audio buffer.
 	fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+		self.apply_to_view(&mut buffer.as_view_mut());
+	}
+
+	/// Apply the effect to a mutable view of audio data.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
 		let mut volume_scale:f32 = 1.0;
 
 		if let Some(target_volume) = self.settings.get::<f32>(SETTING_VOLUME_TARGET) {
@@ -49,7 +53,7 @@ impl AudioEffect for AudioAmplifier {
 			volume_scale *= volume_multiplier;
 		}
 
-		Self::amplify_raw_data(&mut buffer.data, volume_scale);
+		Self::amplify_raw_data(buffer.data, volume_scale);
 	}
 
 	/// Get the settings.
@@ -61,4 +61,11 @@ mod test {
 		AudioAmplifier::new_maximizer(2.0).apply_to(&mut buffer);
 		assert_eq!(buffer.data, (0..100).map(|index| index as f32 / 99.0 * 2.0).collect::<Vec<f32>>());
 	}
+
+	#[test]
+	fn amplify_view() {
+		let mut buffer:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32).collect(), 2, 1000);
+		AudioAmplifier::new(2.0).apply_to_view(&mut buffer.view_frames_mut(10..20));
+		assert_eq!(buffer.data, (0..100).map(|index| if (20..40).contains(&index) { index as f32 * 2.0 } else { index as f32 }).collect::<Vec<f32>>());
+	}
 }
\ No newline at end of file
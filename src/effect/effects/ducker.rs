@@ -0,0 +1,132 @@
+use crate::{ AudioBuffer, AudioBufferAddition, AudioBufferMut, AudioEffect, AudioSettings, OverflowStrategy, RawAddition, analysis::decibels_to_gain };
+
+
+
+const SETTING_THRESHOLD_DB:&str = "threshold_db";
+const SETTING_DEPTH_DB:&str = "depth_db";
+const SETTING_ATTACK_DURATION:&str = "attack_ms";
+const SETTING_HOLD_DURATION:&str = "hold_ms";
+const SETTING_RELEASE_DURATION:&str = "release_ms";
+const DETECTOR_RELEASE_SECONDS:f32 = 0.01;
+
+
+
+pub struct Ducker {
+	settings:AudioSettings,
+	key:AudioBuffer,
+	key_position:usize,
+	envelope:f32,
+	gain:f32,
+	hold_remaining:usize
+}
+impl Ducker {
+
+	/// Create a new ducker. Lowers the audio by the depth in decibels whenever the envelope of the key exceeds the threshold in decibels. The key starts at the first frame of the first processed audio.
+	pub fn new(key:AudioBuffer, threshold_db:f32, depth_db:f32) -> Ducker {
+		Ducker {
+			settings: AudioSettings::new((
+				(SETTING_THRESHOLD_DB, threshold_db),
+				(SETTING_DEPTH_DB, depth_db),
+				(SETTING_ATTACK_DURATION, 10_u64),
+				(SETTING_HOLD_DURATION, 100_u64),
+				(SETTING_RELEASE_DURATION, 250_u64)
+			)),
+			key,
+			key_position: 0,
+			envelope: 0.0,
+			gain: 1.0,
+			hold_remaining: 0
+		}
+	}
+
+	/// Get the key audio.
+	pub fn key(&self) -> &AudioBuffer {
+		&self.key
+	}
+
+	/// Replace the key audio and restart from its first frame.
+	pub fn set_key(&mut self, key:AudioBuffer) {
+		self.key = key;
+		self.reset();
+	}
+}
+impl AudioEffect for Ducker {
+
+	/// Apply the effect to an audio buffer.
+	fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+		self.apply_to_view(&mut buffer.as_view_mut());
+	}
+
+	/// Apply the effect to a mutable view of audio data. Consecutive views continue where the key left off.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
+		if self.key.sample_rate != buffer.sample_rate {
+			self.key_position = (self.key_position as f64 * buffer.sample_rate as f64 / self.key.sample_rate as f64).round() as usize;
+			self.key.resample(self.key.channel_count, buffer.sample_rate);
+		}
+		let frames_per_ms:f32 = buffer.sample_rate as f32 / 1000.0;
+		let threshold:f32 = decibels_to_gain(self.settings.get_or(SETTING_THRESHOLD_DB, -30.0));
+		let ducked_gain:f32 = decibels_to_gain(-self.settings.get_or::<f32>(SETTING_DEPTH_DB, 12.0).abs());
+		let attack_step:f32 = (1.0 - ducked_gain) / (self.settings.get_or::<u64>(SETTING_ATTACK_DURATION, 10) as f32 * frames_per_ms).max(1.0);
+		let release_step:f32 = (1.0 - ducked_gain) / (self.settings.get_or::<u64>(SETTING_RELEASE_DURATION, 250) as f32 * frames_per_ms).max(1.0);
+		let hold_frame_count:usize = (self.settings.get_or::<u64>(SETTING_HOLD_DURATION, 100) as f32 * frames_per_ms) as usize;
+		let detector_decay:f32 = (-1.0 / (DETECTOR_RELEASE_SECONDS * buffer.sample_rate as f32)).exp();
+
+		for frame in buffer.frames_mut() {
+
+			// Follow the peak envelope of the key.
+			let key_peak:f32 = self.key.frame(self.key_position).map(|key_frame| key_frame.iter().fold(0.0, |peak, sample| sample.abs().max(peak))).unwrap_or(0.0);
+			self.key_position += 1;
+			self.envelope = key_peak.max(self.envelope * detector_decay);
+
+			// Move the gain towards the ducked gain while the key is above the threshold or holding.
+			if self.envelope > threshold {
+				self.hold_remaining = hold_frame_count;
+			}
+			if self.envelope > threshold || self.hold_remaining > 0 {
+				self.hold_remaining = self.hold_remaining.saturating_sub(1);
+				self.gain = (self.gain - attack_step).max(ducked_gain);
+			} else {
+				self.gain = (self.gain + release_step).min(1.0);
+			}
+			frame.iter_mut().for_each(|sample| *sample *= self.gain);
+		}
+	}
+
+	/// Restart the key from its first frame and release any gain reduction.
+	fn reset(&mut self) {
+		self.key_position = 0;
+		self.envelope = 0.0;
+		self.gain = 1.0;
+		self.hold_remaining = 0;
+	}
+
+	/// Get the settings.
+	fn settings(&self) -> &AudioSettings {
+		&self.settings
+	}
+
+	/// Get the settings mutably.
+	fn settings_mut(&mut self) -> &mut AudioSettings {
+		&mut self.settings
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Return the audio ducked under the addition and combined with it.
+	pub fn combined_with_ducking<T:AudioBufferAddition>(mut self, addition:T, ducker:&mut Ducker) -> Self {
+		self.combine_with_ducking(addition, ducker);
+		self
+	}
+
+	/// Lower the audio wherever the addition is loud using the ducker, then combine the audio with the addition. The ducker is keyed by the placed addition, so its offset and gain are taken into account.
+	pub fn combine_with_ducking<T:AudioBufferAddition>(&mut self, addition:T, ducker:&mut Ducker) {
+		let raw_addition:Vec<RawAddition> = addition.into_placed_list(self.channel_count, self.sample_rate);
+		let key:AudioBuffer = self.with_data(Vec::new()).combined_with_overflow(raw_addition.clone(), OverflowStrategy::None);
+		ducker.set_key(key);
+		ducker.apply_to(self);
+		self.combine_raw(raw_addition, OverflowStrategy::default());
+	}
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioEffect, Ducker, PlacedAddition };
+
+
+
+	fn key_with_burst(frame_count:usize, burst_start:usize, burst_end:usize) -> AudioBuffer {
+		AudioBuffer::new((0..frame_count).map(|index| if index >= burst_start && index < burst_end { 0.5 } else { 0.0 }).collect(), 1, 1000)
+	}
+
+
+
+	#[test]
+	fn ducker_attack_hold_release() {
+		let mut calculated_buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 400], 1, 1000);
+		let mut ducker:Ducker = Ducker::new(key_with_burst(400, 100, 150), -20.0, 20.0);
+		ducker.settings_mut().set_m(vec![("attack_ms", 10_u64), ("hold_ms", 50_u64), ("release_ms", 100_u64)]);
+		ducker.apply_to(&mut calculated_buffer);
+
+		assert_eq!(calculated_buffer.data[..100], [1.0; 100]);
+		assert!((calculated_buffer.data[104] - 0.55).abs() < 0.0001);
+		assert!(calculated_buffer.data[110..210].iter().all(|sample| (*sample - 0.1).abs() < 0.0001));
+		assert!(calculated_buffer.data[220..310].windows(2).all(|pair| pair[1] > pair[0]));
+		assert!(calculated_buffer.data[320..].iter().all(|sample| *sample == 1.0));
+	}
+
+	#[test]
+	fn ducker_ignores_quiet_key() {
+		let mut calculated_buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 200], 2, 1000);
+		let mut ducker:Ducker = Ducker::new(AudioBuffer::new(vec![0.01; 200], 1, 1000), -20.0, 20.0);
+		ducker.apply_to(&mut calculated_buffer);
+		assert_eq!(calculated_buffer.data, vec![1.0; 200]);
+	}
+
+	#[test]
+	fn ducker_continues_between_views() {
+		let mut whole:AudioBuffer = AudioBuffer::new(vec![1.0; 400], 1, 1000);
+		Ducker::new(key_with_burst(400, 100, 150), -20.0, 12.0).apply_to(&mut whole);
+
+		let mut parts:AudioBuffer = AudioBuffer::new(vec![1.0; 400], 1, 1000);
+		let mut ducker:Ducker = Ducker::new(key_with_burst(400, 100, 150), -20.0, 12.0);
+		ducker.apply_to_view(&mut parts.view_frames_mut(0..130));
+		ducker.apply_to_view(&mut parts.view_frames_mut(130..400));
+		assert_eq!(parts, whole);
+	}
+
+	#[test]
+	fn ducker_keeps_key_position_when_resampling_key() {
+		let mut ducker:Ducker = Ducker::new(key_with_burst(400, 100, 150), -20.0, 20.0);
+		ducker.apply_to(&mut AudioBuffer::new(vec![1.0; 50], 1, 1000));
+
+		let mut calculated_buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 400], 1, 2000);
+		ducker.apply_to(&mut calculated_buffer);
+		assert_eq!(ducker.key().sample_rate(), 2000);
+		assert_eq!(calculated_buffer.data[..99], [1.0; 99]);
+		assert!(calculated_buffer.data[120] < 0.2);
+	}
+
+	#[test]
+	fn combine_with_ducking_uses_placed_addition() {
+		let music:AudioBuffer = AudioBuffer::new(vec![0.5; 400], 1, 1000);
+		let voice:Vec<f32> = vec![0.5; 50];
+		let mut ducker:Ducker = Ducker::new(AudioBuffer::new(Vec::new(), 1, 1000), -20.0, 20.0);
+		let mixed:AudioBuffer = music.combined_with_ducking(PlacedAddition::at_frame(voice, 100), &mut ducker);
+		assert_eq!(mixed.data[..100], [0.5; 100]);
+		assert!(mixed.data[110..150].iter().all(|sample| (*sample - 0.55).abs() < 0.0001));
+		assert_eq!(ducker.key().frame_count(), 150);
+	}
+}
@@ -0,0 +1,98 @@
+use crate::{ AudioBuffer, AudioBufferMut, AudioEffect, AudioSettings, FadeCurve, GainEnvelope };
+use std::time::Duration;
+
+
+
+const SETTING_CURVE:&str = "curve";
+const SETTING_BREAKPOINT_COUNT:&str = "breakpoint_count";
+const FADE_CURVES:[FadeCurve; 5] = [FadeCurve::Linear, FadeCurve::EqualPower, FadeCurve::SCurve, FadeCurve::Exponential, FadeCurve::Logarithmic];
+
+
+
+pub struct Fader {
+	settings:AudioSettings,
+	position:usize
+}
+impl Fader {
+
+	/// Create a new fader. Applies the gain envelope, starting at the first frame of the first processed audio. The envelope is stored in the settings as a curve index and numbered breakpoints in milliseconds and linear gain.
+	pub fn new(envelope:GainEnvelope) -> Fader {
+		let mut fader:Fader = Fader {
+			settings: AudioSettings::default(),
+			position: 0
+		};
+		fader.set_envelope(&envelope);
+		fader
+	}
+
+	/// Create a fader that fades in from silence over the given duration.
+	pub fn fade_in(duration:Duration, curve:FadeCurve) -> Fader {
+		Fader::new(GainEnvelope::fade_in(duration, curve))
+	}
+
+	/// Create a fader that fades out to silence over the given duration, starting at the given time.
+	pub fn fade_out(start:Duration, duration:Duration, curve:FadeCurve) -> Fader {
+		Fader::new(GainEnvelope::fade_out(start, duration, curve))
+	}
+
+	/// Get the gain envelope described by the settings.
+	pub fn envelope(&self) -> GainEnvelope {
+		let breakpoint_count:u64 = self.settings.get_or(SETTING_BREAKPOINT_COUNT, 0);
+		let breakpoints:Vec<(Duration, f32)> = (0..breakpoint_count).map(|index| (
+			Duration::from_secs_f64(self.settings.get_or::<f64>(&breakpoint_time_setting(index), 0.0).max(0.0) / 1000.0),
+			self.settings.get_or(&breakpoint_gain_setting(index), 1.0)
+		)).collect();
+		let curve:FadeCurve = FADE_CURVES.get(self.settings.get_or::<u8>(SETTING_CURVE, 0) as usize).copied().unwrap_or(FadeCurve::Linear);
+		GainEnvelope::new(breakpoints).with_curve(curve)
+	}
+
+	/// Replace the gain envelope in the settings.
+	pub fn set_envelope(&mut self, envelope:&GainEnvelope) {
+		self.settings.set(SETTING_CURVE, FADE_CURVES.iter().position(|curve| *curve == envelope.curve()).unwrap_or(0) as u8);
+		self.settings.set(SETTING_BREAKPOINT_COUNT, envelope.breakpoints().len() as u64);
+		for (index, (time, gain)) in envelope.breakpoints().iter().enumerate() {
+			self.settings.set(&breakpoint_time_setting(index as u64), time.as_secs_f64() * 1000.0);
+			self.settings.set(&breakpoint_gain_setting(index as u64), *gain);
+		}
+	}
+}
+impl AudioEffect for Fader {
+
+	/// Apply the effect to an audio buffer.
+	fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+		self.apply_to_view(&mut buffer.as_view_mut());
+	}
+
+	/// Apply the effect to a mutable view of audio data. Consecutive views continue where the envelope left off.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
+		self.envelope().apply_interleaved(buffer.data, buffer.channel_count, buffer.sample_rate, self.position);
+		self.position += buffer.data.len() / buffer.channel_count;
+	}
+
+	/// Restart the envelope from the beginning.
+	fn reset(&mut self) {
+		self.position = 0;
+	}
+
+	/// Get the settings.
+	fn settings(&self) -> &AudioSettings {
+		&self.settings
+	}
+
+	/// Get the settings mutably.
+	fn settings_mut(&mut self) -> &mut AudioSettings {
+		&mut self.settings
+	}
+}
+
+
+
+/// Get the name of the setting holding the time of a breakpoint in milliseconds.
+fn breakpoint_time_setting(index:u64) -> String {
+	format!("breakpoint_{index}_ms")
+}
+
+/// Get the name of the setting holding the linear gain of a breakpoint.
+fn breakpoint_gain_setting(index:u64) -> String {
+	format!("breakpoint_{index}_gain")
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, AudioEffect, FadeCurve, Fader, GainEnvelope };
+
+
+
+	#[test]
+	fn fader_fade_in() {
+		let mut calculated_buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 8], 2, 100);
+		Fader::fade_in(Duration::from_millis(20), FadeCurve::Linear).apply_to(&mut calculated_buffer);
+
+		let expected_buffer:AudioBuffer = AudioBuffer::new(vec![0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0], 2, 100);
+
+		calculated_buffer.assert_similar(&expected_buffer, 0.0001);
+	}
+
+	#[test]
+	fn fader_envelope_in_settings() {
+		let envelope:GainEnvelope = GainEnvelope::fade_out(Duration::from_millis(10), Duration::from_millis(20), FadeCurve::SCurve);
+		let mut fader:Fader = Fader::new(envelope.clone());
+		assert_eq!(fader.envelope(), envelope);
+
+		fader.settings_mut().set_m(vec![("breakpoint_0_ms", 0.0_f64), ("breakpoint_1_ms", 20.0_f64)]);
+		fader.settings_mut().set("curve", 0_u8);
+		assert_eq!(fader.envelope(), GainEnvelope::fade_out(Duration::ZERO, Duration::from_millis(20), FadeCurve::Linear));
+
+		let mut calculated_buffer:AudioBuffer = AudioBuffer::new(vec![1.0; 4], 1, 100);
+		fader.apply_to(&mut calculated_buffer);
+		calculated_buffer.assert_similar(&AudioBuffer::new(vec![1.0, 0.5, 0.0, 0.0], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn fader_continues_between_views() {
+		let envelope:GainEnvelope = GainEnvelope::new(vec![(Duration::ZERO, 1.0), (Duration::from_millis(100), 0.0)]).with_curve(FadeCurve::SCurve);
+		let whole:AudioBuffer = AudioBuffer::new(vec![1.0; 20], 1, 100).enveloped(&envelope);
+
+		let mut parts:AudioBuffer = AudioBuffer::new(vec![1.0; 20], 1, 100);
+		let mut fader:Fader = Fader::new(envelope);
+		fader.apply_to_view(&mut parts.view_frames_mut(0..7));
+		fader.apply_to_view(&mut parts.view_frames_mut(7..20));
+		parts.assert_similar(&whole, 0.0001);
+
+		fader.reset();
+		let mut restarted:AudioBuffer = AudioBuffer::new(vec![1.0; 20], 1, 100);
+		fader.apply_to(&mut restarted);
+		restarted.assert_similar(&whole, 0.0001);
+	}
+}
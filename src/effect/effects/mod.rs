@@ -1,7 +1,16 @@
 mod amplifier;
 mod amplifier_u;
+mod ducker;
+mod ducker_u;
+mod fader;
+mod fader_u;
 mod noise_gate;
 mod noise_gate_u;
+mod pitch_shifter;
+mod pitch_shifter_u;
 
 pub use amplifier::*;
-pub use noise_gate::*;
\ No newline at end of file
+pub use ducker::*;
+pub use fader::*;
+pub use noise_gate::*;
+pub use pitch_shifter::*;
\ No newline at end of file
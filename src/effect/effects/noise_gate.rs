@@ -1,4 +1,4 @@
-use crate::{ AudioBuffer, AudioEffect, AudioSettings };
+use crate::{ AudioBuffer, AudioBufferMut, AudioEffect, AudioSettings };
 
 
 
@@ -35,6 +35,11 @@ impl AudioEffect for NoiseGate {
 
 	/// Apply the effect to an audio buffer.
 	fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+		self.apply_to_view(&mut buffer.as_view_mut());
+	}
+
+	/// Apply the effect to a mutable view of audio data.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
 
 		// Update cache.
 		let update_cache:bool = self.cache.as_ref().map(|cache| cache.sample_rate != buffer.sample_rate).unwrap_or(true);
@@ -0,0 +1,55 @@
+use crate::{ AudioBuffer, AudioBufferMut, AudioEffect, AudioSettings, TimeStretchMode, pitch_shifting::{ pitch_ratio, pitch_shift_interleaved } };
+
+
+
+const SETTING_SEMITONES:&str = "semitones";
+const SETTING_CENTS:&str = "cents";
+
+
+
+pub struct PitchShifter {
+	settings:AudioSettings,
+	mode:TimeStretchMode
+}
+impl PitchShifter {
+
+	/// Create a new pitch-shifter. Shifts the pitch by the given amount of semitones while keeping the duration.
+	pub fn new(semitones:f32) -> PitchShifter {
+		PitchShifter::new_with_mode(semitones, TimeStretchMode::default())
+	}
+
+	/// Create a new pitch-shifter that uses the given time-stretch mode.
+	pub fn new_with_mode(semitones:f32, mode:TimeStretchMode) -> PitchShifter {
+		PitchShifter {
+			settings: AudioSettings::new((
+				(SETTING_SEMITONES, semitones),
+				(SETTING_CENTS, 0.0_f32)
+			)),
+			mode
+		}
+	}
+}
+impl AudioEffect for PitchShifter {
+
+	/// Apply the effect to an audio buffer.
+	fn apply_to(&mut self, buffer:&mut AudioBuffer) {
+		self.apply_to_view(&mut buffer.as_view_mut());
+	}
+
+	/// Apply the effect to a mutable view of audio data. Each view is shifted on its own, so apply it to whole buffers to avoid seams.
+	fn apply_to_view(&mut self, buffer:&mut AudioBufferMut) {
+		let pitch_ratio:f64 = pitch_ratio(self.settings.get_or(SETTING_SEMITONES, 0.0), self.settings.get_or(SETTING_CENTS, 0.0));
+		let shifted:Vec<f32> = pitch_shift_interleaved(buffer.data, buffer.channel_count, buffer.sample_rate, pitch_ratio, self.mode);
+		buffer.data.copy_from_slice(&shifted);
+	}
+
+	/// Get the settings.
+	fn settings(&self) -> &AudioSettings {
+		&self.settings
+	}
+
+	/// Get the settings mutably.
+	fn settings_mut(&mut self) -> &mut AudioSettings {
+		&mut self.settings
+	}
+}
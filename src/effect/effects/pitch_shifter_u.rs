@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod test {
+	use crate::{ PitchShifter, AudioBuffer, AudioEffect, TimeStretchMode, test_helpers::mono_sine_wave };
+
+
+
+	#[test]
+	fn pitch_shifter_matches_method() {
+		let mut calculated_buffer:AudioBuffer = mono_sine_wave(440.0, 4000, 8000);
+		let mut pitch_shifter:PitchShifter = PitchShifter::new(7.0);
+		pitch_shifter.apply_to(&mut calculated_buffer);
+
+		let expected_buffer:AudioBuffer = mono_sine_wave(440.0, 4000, 8000).pitch_shifted(7.0, 0.0, TimeStretchMode::Wsola);
+
+		calculated_buffer.assert_similar(&expected_buffer, 0.0001);
+	}
+
+	#[test]
+	fn pitch_shifter_uses_settings() {
+		let mut calculated_buffer:AudioBuffer = mono_sine_wave(440.0, 4000, 8000);
+		let mut pitch_shifter:PitchShifter = PitchShifter::new_with_mode(0.0, TimeStretchMode::PhaseVocoder);
+		pitch_shifter.settings_mut().set_m(vec![("semitones", -2.0_f32), ("cents", 25.0_f32)]);
+		pitch_shifter.apply_to(&mut calculated_buffer);
+
+		let expected_buffer:AudioBuffer = mono_sine_wave(440.0, 4000, 8000).pitch_shifted(-2.0, 25.0, TimeStretchMode::PhaseVocoder);
+
+		calculated_buffer.assert_similar(&expected_buffer, 0.0001);
+	}
+
+	#[test]
+	fn pitch_shifter_zero_is_unchanged() {
+		let mut calculated_buffer:AudioBuffer = mono_sine_wave(440.0, 1000, 8000);
+		PitchShifter::new(0.0).apply_to(&mut calculated_buffer);
+		calculated_buffer.assert_similar(&mono_sine_wave(440.0, 1000, 8000), 0.0001);
+	}
+}
@@ -1,4 +1,5 @@
 mod effect;
+mod effect_u;
 mod effect_settings;
 mod effect_settings_generated;
 
@@ -0,0 +1,32 @@
+use std::{ error::Error, fmt::{ self, Display, Formatter } };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum AudioBufferError {
+	NoChannels,
+	NoSampleRate,
+	MisalignedData { sample_count:usize, channel_count:usize },
+	LayoutMismatch { layout_channel_count:usize, channel_count:usize },
+	MatrixMismatch { matrix_channel_count:usize, channel_count:usize },
+	InvalidEditList { line_index:usize },
+	UnknownEffect { name:String },
+	InvalidEffectName { name:String },
+	UnevenChannels { channel_index:usize, sample_count:usize, expected_sample_count:usize }
+}
+impl Display for AudioBufferError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		match self {
+			AudioBufferError::NoChannels => write!(f, "Audio buffer has a channel count of zero."),
+			AudioBufferError::NoSampleRate => write!(f, "Audio buffer has a sample rate of zero."),
+			AudioBufferError::MisalignedData { sample_count, channel_count } => write!(f, "Audio buffer data length {sample_count} is not a multiple of the channel count {channel_count}."),
+			AudioBufferError::LayoutMismatch { layout_channel_count, channel_count } => write!(f, "Audio buffer channel layout has {layout_channel_count} channels, but the buffer has {channel_count}."),
+			AudioBufferError::MatrixMismatch { matrix_channel_count, channel_count } => write!(f, "Channel matrix expects {matrix_channel_count} channels, but the buffer has {channel_count}."),
+			AudioBufferError::InvalidEditList { line_index } => write!(f, "Edit list line {line_index} is not a valid operation."),
+			AudioBufferError::UnknownEffect { name } => write!(f, "Edit list uses effect \"{name}\", which is not registered."),
+			AudioBufferError::InvalidEffectName { name } => write!(f, "Effect name \"{name}\" is empty or contains whitespace."),
+			AudioBufferError::UnevenChannels { channel_index, sample_count, expected_sample_count } => write!(f, "Planar channel {channel_index} has {sample_count} samples, but the first channel has {expected_sample_count}.")
+		}
+	}
+}
+impl Error for AudioBufferError {}
@@ -0,0 +1,194 @@
+use crate::AudioBuffer;
+use std::{ f32::consts::{ FRAC_PI_2, PI }, time::Duration };
+
+
+
+const EXPONENTIAL_RANGE_DB:f32 = 60.0;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum FadeCurve {
+	/// Gain changes linearly.
+	Linear,
+
+	/// Gain follows a quarter sine, so a crossfade between uncorrelated audio keeps a constant power.
+	#[default]
+	EqualPower,
+
+	/// Gain follows a half cosine, starting and ending smoothly.
+	SCurve,
+
+	/// Gain changes linearly in decibels over a 60 dB range, starting slowly.
+	Exponential,
+
+	/// Mirror of the exponential curve, starting quickly.
+	Logarithmic
+}
+impl FadeCurve {
+
+	/// Get the gain of a fade-in at the given progress from 0.0 to 1.0. A fade-out uses the gain at the remaining progress.
+	pub fn gain(&self, progress:f32) -> f32 {
+		let progress:f32 = progress.clamp(0.0, 1.0);
+		match self {
+			FadeCurve::Linear => progress,
+			FadeCurve::EqualPower => (progress * FRAC_PI_2).sin(),
+			FadeCurve::SCurve => (1.0 - (progress * PI).cos()) / 2.0,
+			FadeCurve::Exponential => exponential_gain(progress),
+			FadeCurve::Logarithmic => 1.0 - exponential_gain(1.0 - progress)
+		}
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct GainEnvelope {
+	breakpoints:Vec<(Duration, f32)>,
+	curve:FadeCurve
+}
+impl GainEnvelope {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create an envelope from breakpoints of time and linear gain. The gain moves between breakpoints linearly and is held before the first and after the last one. Without breakpoints the gain is 1.0.
+	pub fn new(mut breakpoints:Vec<(Duration, f32)>) -> GainEnvelope {
+		breakpoints.sort_by_key(|(time, _)| *time);
+		GainEnvelope {
+			breakpoints,
+			curve: FadeCurve::Linear
+		}
+	}
+
+	/// Create an envelope that fades in from silence over the given duration.
+	pub fn fade_in(duration:Duration, curve:FadeCurve) -> GainEnvelope {
+		GainEnvelope::new(vec![(Duration::ZERO, 0.0), (duration, 1.0)]).with_curve(curve)
+	}
+
+	/// Create an envelope that fades out to silence over the given duration, starting at the given time.
+	pub fn fade_out(start:Duration, duration:Duration, curve:FadeCurve) -> GainEnvelope {
+		GainEnvelope::new(vec![(start, 1.0), (start + duration, 0.0)]).with_curve(curve)
+	}
+
+	/// Return the envelope using the given curve between breakpoints. Falling segments use the mirrored curve, so fades in and out have the same shape.
+	pub fn with_curve(mut self, curve:FadeCurve) -> Self {
+		self.curve = curve;
+		self
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the breakpoints of the envelope.
+	pub fn breakpoints(&self) -> &[(Duration, f32)] {
+		&self.breakpoints
+	}
+
+	/// Get the curve used between breakpoints.
+	pub fn curve(&self) -> FadeCurve {
+		self.curve
+	}
+
+	/// Get the gain at the given time.
+	pub fn gain_at(&self, time:Duration) -> f32 {
+		let next_index:usize = self.breakpoints.partition_point(|(breakpoint_time, _)| *breakpoint_time <= time);
+		match (next_index.checked_sub(1).map(|index| self.breakpoints[index]), self.breakpoints.get(next_index).copied()) {
+			(None, None) => 1.0,
+			(Some((_, gain)), None) | (None, Some((_, gain))) => gain,
+			(Some((previous_time, previous_gain)), Some((next_time, next_gain))) => {
+				let progress:f32 = ((time - previous_time).as_secs_f64() / (next_time - previous_time).as_secs_f64()) as f32;
+				if next_gain >= previous_gain {
+					previous_gain + (next_gain - previous_gain) * self.curve.gain(progress)
+				} else {
+					next_gain + (previous_gain - next_gain) * self.curve.gain(1.0 - progress)
+				}
+			}
+		}
+	}
+
+	/// Apply the envelope to interleaved data. The first frame of the data lies at the given frame of the envelope.
+	pub(crate) fn apply_interleaved(&self, data:&mut [f32], channel_count:usize, sample_rate:u32, first_frame_index:usize) {
+		for (frame_index, frame) in data.chunks_exact_mut(channel_count).enumerate() {
+			let gain:f32 = self.gain_at(Duration::from_secs_f64((first_frame_index + frame_index) as f64 / sample_rate as f64));
+			frame.iter_mut().for_each(|sample| *sample *= gain);
+		}
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Return the audio faded in from silence over the given duration.
+	pub fn faded_in(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.fade_in(duration, curve);
+		self
+	}
+
+	/// Fade in the audio from silence over the given duration.
+	pub fn fade_in(&mut self, duration:Duration, curve:FadeCurve) {
+		let fade_frame_count:usize = self.duration_to_frames(duration);
+		fade_in_interleaved(&mut self.data, self.channel_count, fade_frame_count, curve);
+	}
+
+	/// Return the audio faded out to silence over the given duration at the end.
+	pub fn faded_out(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.fade_out(duration, curve);
+		self
+	}
+
+	/// Fade out the audio to silence over the given duration at the end.
+	pub fn fade_out(&mut self, duration:Duration, curve:FadeCurve) {
+		let fade_frame_count:usize = self.duration_to_frames(duration);
+		fade_out_to_silence_interleaved(&mut self.data, self.channel_count, fade_frame_count, curve);
+	}
+
+	/// Return the audio with the gain envelope applied.
+	pub fn enveloped(mut self, envelope:&GainEnvelope) -> Self {
+		self.apply_envelope(envelope);
+		self
+	}
+
+	/// Apply a gain envelope to the audio. The envelope starts at the first frame.
+	pub fn apply_envelope(&mut self, envelope:&GainEnvelope) {
+		envelope.apply_interleaved(&mut self.data, self.channel_count, self.sample_rate, 0);
+	}
+}
+
+
+
+/// Exponential fade gain, rescaled so it starts at exactly zero.
+fn exponential_gain(progress:f32) -> f32 {
+	let floor:f32 = 10.0_f32.powf(-EXPONENTIAL_RANGE_DB / 20.0);
+	(10.0_f32.powf((progress - 1.0) * EXPONENTIAL_RANGE_DB / 20.0) - floor) / (1.0 - floor)
+}
+
+/// Fade in the first frames of interleaved data. The first frame is silent.
+pub(crate) fn fade_in_interleaved(data:&mut [f32], channel_count:usize, fade_frame_count:usize, curve:FadeCurve) {
+	for (frame_index, frame) in data.chunks_exact_mut(channel_count).take(fade_frame_count).enumerate() {
+		let gain:f32 = curve.gain(frame_index as f32 / fade_frame_count as f32);
+		frame.iter_mut().for_each(|sample| *sample *= gain);
+	}
+}
+
+/// Fade out the last frames of interleaved data. The last frame is silent.
+pub(crate) fn fade_out_to_silence_interleaved(data:&mut [f32], channel_count:usize, fade_frame_count:usize, curve:FadeCurve) {
+	let frame_count:usize = data.len() / channel_count;
+	let fade_frame_count:usize = fade_frame_count.min(frame_count);
+	let last_fade_frame_index:f32 = fade_frame_count.saturating_sub(1).max(1) as f32;
+	for (frame_index, frame) in data.chunks_exact_mut(channel_count).skip(frame_count - fade_frame_count).enumerate() {
+		let gain:f32 = curve.gain((fade_frame_count - 1 - frame_index) as f32 / last_fade_frame_index);
+		frame.iter_mut().for_each(|sample| *sample *= gain);
+	}
+}
+
+/// Fade out the last frames of interleaved data for a crossfade. The gains mirror a fade-in of the same length, so the two form a crossfade when overlapped.
+pub(crate) fn fade_out_interleaved(data:&mut [f32], channel_count:usize, fade_frame_count:usize, curve:FadeCurve) {
+	let frame_count:usize = data.len() / channel_count;
+	let fade_frame_count:usize = fade_frame_count.min(frame_count);
+	for (frame_index, frame) in data.chunks_exact_mut(channel_count).skip(frame_count - fade_frame_count).enumerate() {
+		let gain:f32 = curve.gain(1.0 - frame_index as f32 / fade_frame_count as f32);
+		frame.iter_mut().for_each(|sample| *sample *= gain);
+	}
+}
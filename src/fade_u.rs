@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, FadeCurve, GainEnvelope };
+
+
+
+	const CURVES:[FadeCurve; 5] = [FadeCurve::Linear, FadeCurve::EqualPower, FadeCurve::SCurve, FadeCurve::Exponential, FadeCurve::Logarithmic];
+
+
+
+	#[test]
+	fn fade_curves_are_monotonic() {
+		for curve in CURVES {
+			assert!(curve.gain(0.0).abs() < 0.000001);
+			assert!((curve.gain(1.0) - 1.0).abs() < 0.000001);
+			assert!((0..=100).map(|step| curve.gain(step as f32 / 100.0)).collect::<Vec<f32>>().windows(2).all(|pair| pair[1] >= pair[0]));
+		}
+	}
+
+	#[test]
+	fn fade_curve_shapes() {
+		assert_eq!(FadeCurve::Linear.gain(0.25), 0.25);
+		assert!((FadeCurve::EqualPower.gain(0.3).powi(2) + FadeCurve::EqualPower.gain(0.7).powi(2) - 1.0).abs() < 0.0001);
+		assert!((FadeCurve::SCurve.gain(0.5) - 0.5).abs() < 0.0001);
+		assert!(FadeCurve::Exponential.gain(0.5) < 0.05);
+		assert!(FadeCurve::Logarithmic.gain(0.5) > 0.95);
+	}
+
+	#[test]
+	fn fade_in_and_out() {
+		let faded:AudioBuffer = AudioBuffer::new(vec![1.0; 10], 1, 100).faded_in(Duration::from_millis(40), FadeCurve::Linear).faded_out(Duration::from_millis(20), FadeCurve::Linear);
+		faded.assert_similar(&AudioBuffer::new(vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn fade_longer_than_buffer() {
+		let faded:AudioBuffer = AudioBuffer::new(vec![1.0; 4], 2, 100).faded_out(Duration::from_secs(1), FadeCurve::Linear);
+		faded.assert_similar(&AudioBuffer::new(vec![1.0, 1.0, 0.0, 0.0], 2, 100), 0.0001);
+	}
+
+	#[test]
+	fn gain_envelope_breakpoints() {
+		let envelope:GainEnvelope = GainEnvelope::new(vec![(Duration::from_millis(40), 0.5), (Duration::from_millis(20), 1.0), (Duration::from_millis(60), 1.5)]);
+		assert_eq!(envelope.gain_at(Duration::ZERO), 1.0);
+		assert_eq!(envelope.gain_at(Duration::from_millis(30)), 0.75);
+		assert_eq!(envelope.gain_at(Duration::from_millis(50)), 1.0);
+		assert_eq!(envelope.gain_at(Duration::from_secs(1)), 1.5);
+		assert_eq!(GainEnvelope::new(Vec::new()).gain_at(Duration::ZERO), 1.0);
+
+		let enveloped:AudioBuffer = AudioBuffer::new(vec![0.5; 8], 1, 100).enveloped(&envelope);
+		enveloped.assert_similar(&AudioBuffer::new(vec![0.5, 0.5, 0.5, 0.375, 0.25, 0.5, 0.75, 0.75], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn gain_envelope_curves_mirror() {
+		let fade_in:GainEnvelope = GainEnvelope::fade_in(Duration::from_millis(100), FadeCurve::Exponential);
+		let fade_out:GainEnvelope = GainEnvelope::fade_out(Duration::from_millis(100), Duration::from_millis(100), FadeCurve::Exponential);
+		for step in 0..=10 {
+			let time:Duration = Duration::from_millis(step * 10);
+			assert!((fade_in.gain_at(time) - fade_out.gain_at(Duration::from_millis(200) - time)).abs() < 0.0001);
+		}
+		assert_eq!(fade_in.gain_at(Duration::from_millis(50)), FadeCurve::Exponential.gain(0.5));
+	}
+}
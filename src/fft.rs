@@ -0,0 +1,105 @@
+use std::{ f64::consts::PI, ops::{ Add, Mul, Sub } };
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(crate) struct Complex {
+	pub re:f64,
+	pub im:f64
+}
+impl Complex {
+
+	/// Create a new complex number.
+	pub fn new(re:f64, im:f64) -> Complex {
+		Complex { re, im }
+	}
+
+	/// Create a complex number from a magnitude and phase.
+	pub fn from_polar(magnitude:f64, phase:f64) -> Complex {
+		Complex::new(magnitude * phase.cos(), magnitude * phase.sin())
+	}
+
+	/// Get the magnitude.
+	pub fn magnitude(&self) -> f64 {
+		self.re.hypot(self.im)
+	}
+
+	/// Get the phase.
+	pub fn phase(&self) -> f64 {
+		self.im.atan2(self.re)
+	}
+}
+impl Add for Complex {
+	type Output = Complex;
+
+	fn add(self, other:Complex) -> Complex {
+		Complex::new(self.re + other.re, self.im + other.im)
+	}
+}
+impl Sub for Complex {
+	type Output = Complex;
+
+	fn sub(self, other:Complex) -> Complex {
+		Complex::new(self.re - other.re, self.im - other.im)
+	}
+}
+impl Mul for Complex {
+	type Output = Complex;
+
+	fn mul(self, other:Complex) -> Complex {
+		Complex::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+	}
+}
+
+
+
+/// Transform the data in place using an iterative radix-2 fast fourier transform. The length of the data should be a power of two. The inverse transform is scaled, so a forward and inverse transform return the original data.
+pub(crate) fn fft(data:&mut [Complex], inverse:bool) {
+	let size:usize = data.len();
+	if size < 2 {
+		return;
+	}
+
+	// Reorder data in bit-reversed order.
+	let mut reversed_index:usize = 0;
+	for index in 1..size {
+		let mut bit:usize = size >> 1;
+		while reversed_index & bit != 0 {
+			reversed_index ^= bit;
+			bit >>= 1;
+		}
+		reversed_index |= bit;
+		if index < reversed_index {
+			data.swap(index, reversed_index);
+		}
+	}
+
+	// Combine butterflies of increasing size.
+	let direction:f64 = if inverse { 1.0 } else { -1.0 };
+	let mut length:usize = 2;
+	while length <= size {
+		let step:Complex = Complex::from_polar(1.0, direction * 2.0 * PI / length as f64);
+		for start in (0..size).step_by(length) {
+			let mut twiddle:Complex = Complex::new(1.0, 0.0);
+			for offset in 0..length / 2 {
+				let even:Complex = data[start + offset];
+				let odd:Complex = data[start + offset + length / 2] * twiddle;
+				data[start + offset] = even + odd;
+				data[start + offset + length / 2] = even - odd;
+				twiddle = twiddle * step;
+			}
+		}
+		length <<= 1;
+	}
+
+	// Scale inverse.
+	if inverse {
+		let scale:f64 = 1.0 / size as f64;
+		data.iter_mut().for_each(|value| *value = Complex::new(value.re * scale, value.im * scale));
+	}
+}
+
+/// Create a periodic Hann window of the given size.
+pub(crate) fn hann_window(size:usize) -> Vec<f64> {
+	(0..size).map(|index| 0.5 - 0.5 * (2.0 * PI * index as f64 / size as f64).cos()).collect()
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod test {
+	use crate::fft::{ Complex, fft, hann_window };
+
+
+
+	#[test]
+	fn fft_finds_frequency() {
+		let mut data:Vec<Complex> = (0..64).map(|index| Complex::new((index as f64 * 5.0 * 2.0 * std::f64::consts::PI / 64.0).cos(), 0.0)).collect();
+		fft(&mut data, false);
+		for (bin, value) in data.iter().enumerate() {
+			let expected:f64 = if bin == 5 || bin == 59 { 32.0 } else { 0.0 };
+			assert!((value.magnitude() - expected).abs() < 0.0001);
+		}
+	}
+
+	#[test]
+	fn fft_round_trip() {
+		let original:Vec<Complex> = (0..128).map(|index| Complex::new((index as f64 * 0.37).sin(), (index as f64 * 0.11).cos())).collect();
+		let mut data:Vec<Complex> = original.clone();
+		fft(&mut data, false);
+		fft(&mut data, true);
+		for (value, original) in data.iter().zip(&original) {
+			assert!((value.re - original.re).abs() < 0.000001 && (value.im - original.im).abs() < 0.000001);
+		}
+	}
+
+	#[test]
+	fn hann_window_overlaps_to_one() {
+		let window:Vec<f64> = hann_window(64);
+		for index in 0..32 {
+			assert!((window[index] + window[index + 32] - 1.0).abs() < 0.000001);
+		}
+	}
+}
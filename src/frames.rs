@@ -0,0 +1,92 @@
+use std::{ iter::StepBy, slice::{ ChunksExact, ChunksExactMut, Iter, IterMut } };
+use crate::{ AudioBuffer, AudioBufferError };
+
+
+
+impl AudioBuffer {
+
+	/* FRAME METHODS */
+
+	/// Get the amount of frames in the buffer. A frame holds one sample for each channel.
+	pub fn frame_count(&self) -> usize {
+		self.data.len() / self.channel_count
+	}
+
+	/// Get a specific frame.
+	pub fn frame(&self, frame_index:usize) -> Option<&[f32]> {
+		self.data.get(frame_index * self.channel_count..(frame_index + 1) * self.channel_count)
+	}
+
+	/// Get a specific frame mutably.
+	pub fn frame_mut(&mut self, frame_index:usize) -> Option<&mut [f32]> {
+		self.data.get_mut(frame_index * self.channel_count..(frame_index + 1) * self.channel_count)
+	}
+
+	/// Iterate over all frames.
+	pub fn frames(&self) -> ChunksExact<'_, f32> {
+		self.data.chunks_exact(self.channel_count)
+	}
+
+	/// Iterate over all frames mutably.
+	pub fn frames_mut(&mut self) -> ChunksExactMut<'_, f32> {
+		self.data.chunks_exact_mut(self.channel_count)
+	}
+
+
+
+	/* CHANNEL METHODS */
+
+	/// Iterate over all samples of a single channel. Yields nothing if the channel does not exist.
+	pub fn channel(&self, channel_index:usize) -> StepBy<Iter<'_, f32>> {
+		let start:usize = self.channel_start(channel_index);
+		self.data[start..].iter().step_by(self.channel_count)
+	}
+
+	/// Iterate over all samples of a single channel mutably. Yields nothing if the channel does not exist.
+	pub fn channel_mut(&mut self, channel_index:usize) -> StepBy<IterMut<'_, f32>> {
+		let start:usize = self.channel_start(channel_index);
+		self.data[start..].iter_mut().step_by(self.channel_count)
+	}
+
+	/// Get the index of the first sample of a channel, or the end of the data if the channel does not exist.
+	fn channel_start(&self, channel_index:usize) -> usize {
+		if channel_index < self.channel_count { channel_index.min(self.data.len()) } else { self.data.len() }
+	}
+
+	/// Iterate over all channels, each being an iterator over its samples.
+	pub fn channels(&self) -> impl Iterator<Item = StepBy<Iter<'_, f32>>> {
+		(0..self.channel_count).map(|channel_index| self.channel(channel_index))
+	}
+
+
+
+	/* PLANAR CONVERSION METHODS */
+
+	/// Get a list of samples for each channel.
+	pub fn to_planar(&self) -> Vec<Vec<f32>> {
+		self.channels().map(|channel| channel.cloned().collect()).collect()
+	}
+
+	/// Create a buffer from a list of samples for each channel. Shorter channels are padded with silence. An empty list creates a buffer without channels, use `try_from_planar` to reject both cases.
+	pub fn from_planar(channels:Vec<Vec<f32>>, sample_rate:u32) -> AudioBuffer {
+		let channel_count:usize = channels.len();
+		let frame_count:usize = channels.iter().map(|channel| channel.len()).max().unwrap_or_default();
+		let mut data:Vec<f32> = vec![0.0; frame_count * channel_count];
+		for (channel_index, channel) in channels.into_iter().enumerate() {
+			for (target, sample) in data[channel_index..].iter_mut().step_by(channel_count).zip(channel) {
+				*target = sample;
+			}
+		}
+		AudioBuffer::new(data, channel_count, sample_rate)
+	}
+
+	/// Try to create a buffer from a list of samples for each channel. Returns an error if there are no channels, the sample rate is zero, or the channels differ in length.
+	pub fn try_from_planar(channels:Vec<Vec<f32>>, sample_rate:u32) -> Result<AudioBuffer, AudioBufferError> {
+		Self::validate_settings(channels.len(), sample_rate)?;
+		let expected_sample_count:usize = channels[0].len();
+		if let Some((channel_index, channel)) = channels.iter().enumerate().find(|(_, channel)| channel.len() != expected_sample_count) {
+			return Err(AudioBufferError::UnevenChannels { channel_index, sample_count: channel.len(), expected_sample_count });
+		}
+		Ok(AudioBuffer::from_planar(channels, sample_rate))
+	}
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferError };
+
+
+
+	fn counting_buffer(frame_count:usize, channel_count:usize) -> AudioBuffer {
+		AudioBuffer::new((0..frame_count * channel_count).map(|index| index as f32).collect(), channel_count, 1000)
+	}
+
+
+
+	#[test]
+	fn frame_count() {
+		assert_eq!(counting_buffer(100, 1).frame_count(), 100);
+		assert_eq!(counting_buffer(100, 3).frame_count(), 100);
+		assert_eq!(counting_buffer(0, 2).frame_count(), 0);
+	}
+
+	#[test]
+	fn frame_access() {
+		let mut buffer:AudioBuffer = counting_buffer(100, 3);
+		assert_eq!(buffer.frame(0), Some(&[0.0, 1.0, 2.0][..]));
+		assert_eq!(buffer.frame(99), Some(&[297.0, 298.0, 299.0][..]));
+		assert_eq!(buffer.frame(100), None);
+
+		buffer.frame_mut(1).unwrap().iter_mut().for_each(|sample| *sample = -1.0);
+		assert_eq!(&buffer.data[..9], &[0.0, 1.0, 2.0, -1.0, -1.0, -1.0, 6.0, 7.0, 8.0]);
+	}
+
+	#[test]
+	fn frame_iterators() {
+		let mut buffer:AudioBuffer = counting_buffer(100, 2);
+		assert_eq!(buffer.frames().count(), 100);
+		assert!(buffer.frames().enumerate().all(|(index, frame)| frame == [index as f32 * 2.0, index as f32 * 2.0 + 1.0]));
+
+		buffer.frames_mut().for_each(|frame| frame.swap(0, 1));
+		assert!(buffer.frames().enumerate().all(|(index, frame)| frame == [index as f32 * 2.0 + 1.0, index as f32 * 2.0]));
+	}
+
+	#[test]
+	fn channel_iterators() {
+		let mut buffer:AudioBuffer = counting_buffer(100, 3);
+		assert_eq!(buffer.channel(1).cloned().collect::<Vec<f32>>(), (0..100).map(|index| (index * 3 + 1) as f32).collect::<Vec<f32>>());
+		assert_eq!(buffer.channels().count(), 3);
+
+		buffer.channel_mut(2).for_each(|sample| *sample = 0.0);
+		assert!(buffer.frames().all(|frame| frame[2] == 0.0 && frame[1] != 0.0));
+	}
+
+	#[test]
+	fn channel_out_of_range() {
+		let mut buffer:AudioBuffer = counting_buffer(10, 2);
+		assert_eq!(buffer.channel(2).count(), 0);
+		assert_eq!(buffer.channel(3).count(), 0);
+		assert_eq!(buffer.channel_mut(3).count(), 0);
+		assert_eq!(buffer.channel(1).count(), 10);
+	}
+
+	#[test]
+	fn planar_round_trip() {
+		let buffer:AudioBuffer = counting_buffer(100, 4);
+		let planar:Vec<Vec<f32>> = buffer.to_planar();
+		assert_eq!(planar.len(), 4);
+		assert_eq!(planar[3], (0..100).map(|index| (index * 4 + 3) as f32).collect::<Vec<f32>>());
+		assert_eq!(AudioBuffer::from_planar(planar, 1000), buffer);
+	}
+
+	#[test]
+	fn from_planar_pads_short_channels() {
+		let buffer:AudioBuffer = AudioBuffer::from_planar(vec![vec![1.0, 2.0, 3.0], vec![4.0]], 1000);
+		assert_eq!(buffer.data, vec![1.0, 4.0, 2.0, 0.0, 3.0, 0.0]);
+	}
+
+	#[test]
+	fn try_from_planar() {
+		assert_eq!(AudioBuffer::try_from_planar(vec![vec![1.0, 2.0], vec![3.0, 4.0]], 1000).unwrap().data, vec![1.0, 3.0, 2.0, 4.0]);
+		assert_eq!(AudioBuffer::try_from_planar(Vec::new(), 1000), Err(AudioBufferError::NoChannels));
+		assert_eq!(AudioBuffer::try_from_planar(vec![vec![1.0, 2.0], vec![3.0]], 1000), Err(AudioBufferError::UnevenChannels { channel_index: 1, sample_count: 1, expected_sample_count: 2 }));
+	}
+
+	#[test]
+	fn frames_skip_partial_frame() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![1.0, 2.0, 3.0], 2, 1000);
+		assert_eq!(buffer.frames().collect::<Vec<&[f32]>>(), vec![&[1.0, 2.0]]);
+	}
+}
@@ -0,0 +1,214 @@
+use crate::polyphase::PolyphaseFilter;
+use std::f64::consts::PI;
+
+
+
+const DEFAULT_SINC_TAPS:usize = 32;
+const DEFAULT_KAISER_BETA:f32 = 8.6;
+const SINC_TABLE_RESOLUTION:usize = 512; // Table entries per zero-crossing of the kernel.
+const MAX_SINC_TAPS:usize = 1024;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResampleQuality {
+	Nearest,
+	Linear,
+	Cubic,
+	WindowedSinc { taps:usize, kaiser_beta:f32 }
+}
+impl Default for ResampleQuality {
+	fn default() -> Self {
+		ResampleQuality::WindowedSinc { taps: DEFAULT_SINC_TAPS, kaiser_beta: DEFAULT_KAISER_BETA }
+	}
+}
+
+
+
+pub(crate) struct Interpolator {
+	quality:ResampleQuality,
+	sinc:Option<SincKernel>
+}
+impl Interpolator {
+
+	/// Create a new interpolator for the given quality and rate ratio. The ratio is the output rate divided by the input rate.
+	pub fn new(quality:ResampleQuality, rate_ratio:f64) -> Interpolator {
+		Interpolator {
+			quality,
+			sinc: match quality {
+				ResampleQuality::WindowedSinc { taps, kaiser_beta } => Some(SincKernel::new(taps, kaiser_beta as f64, rate_ratio.min(1.0))),
+				_ => None
+			}
+		}
+	}
+
+	/// The amount of input frames on each side of a position that may be used to interpolate it.
+	pub fn reach(&self) -> usize {
+		match (&self.quality, &self.sinc) {
+			(ResampleQuality::Cubic, _) => 2,
+			(_, Some(sinc)) => sinc.reach() + 1,
+			_ => 1
+		}
+	}
+
+	/// Get the quality of the interpolator.
+	pub fn quality(&self) -> ResampleQuality {
+		self.quality
+	}
+
+	/// Get the weights of the input frames used to interpolate the given position. The weights replace the contents of the given list, so one list can be reused for every frame. Frame indexes may lie outside of the available data.
+	pub fn weights(&self, position:f64, weights:&mut Vec<(isize, f64)>) {
+		let base:isize = position.floor() as isize;
+		let fraction:f64 = position - position.floor();
+		weights.clear();
+		match (&self.quality, &self.sinc) {
+			(ResampleQuality::Nearest, _) => weights.push((position.round() as isize, 1.0)),
+			(ResampleQuality::Linear, _) => weights.extend([(base, 1.0 - fraction), (base + 1, fraction)]),
+			(ResampleQuality::Cubic, _) => {
+				let fraction_2:f64 = fraction * fraction;
+				let fraction_3:f64 = fraction_2 * fraction;
+				weights.extend([
+					(base - 1, -0.5 * fraction_3 + fraction_2 - 0.5 * fraction),
+					(base, 1.5 * fraction_3 - 2.5 * fraction_2 + 1.0),
+					(base + 1, -1.5 * fraction_3 + 2.0 * fraction_2 + 0.5 * fraction),
+					(base + 2, 0.5 * fraction_3 - 0.5 * fraction_2)
+				]);
+			},
+			(_, Some(sinc)) if sinc.cutoff >= 1.0 && fraction == 0.0 => weights.push((base, 1.0)),
+			(_, Some(sinc)) => {
+				let reach:isize = sinc.reach() as isize;
+				weights.extend((base - reach..=base + reach + 1).map(|frame_index| (frame_index, sinc.weight(position - frame_index as f64))).filter(|(_, weight)| *weight != 0.0));
+			},
+			(_, None) => weights.push((base, 1.0))
+		}
+	}
+
+	/// Interpolate a single frame at the given position in interleaved data. The weights list is used as scratch space, so one list can be reused for every frame. Frames outside of the data are extrapolated by point reflection around the edge frames, which keeps constant and linear signals intact. Nearest interpolation uses the edge frames instead.
+	pub fn interpolate_frame(&self, data:&[f32], channel_count:usize, position:f64, output:&mut [f32], weights:&mut Vec<(isize, f64)>) {
+		let last_frame_index:isize = (data.len() / channel_count) as isize - 1;
+		self.weights(position, weights);
+		if self.quality == ResampleQuality::Nearest {
+			weights.iter_mut().for_each(|(frame_index, _)| *frame_index = (*frame_index).clamp(0, last_frame_index));
+		}
+		for (channel_index, output_sample) in output.iter_mut().enumerate() {
+			*output_sample = weights.iter().map(|(frame_index, weight)| reflected_sample(data, channel_count, *frame_index, channel_index) * weight).sum::<f64>() as f32;
+		}
+	}
+}
+
+
+
+pub(crate) struct SincKernel {
+	table:Vec<f64>,
+	table_resolution:f64,
+	cutoff:f64,
+	half_width:f64
+}
+impl SincKernel {
+
+	/// Create a new Kaiser-windowed sinc kernel. The cutoff is relative to the input Nyquist frequency, a lower cutoff widens the kernel to keep the same amount of zero-crossings. The table is sampled per zero-crossing, so its size only depends on the amount of taps, which is limited to 1024.
+	pub fn new(taps:usize, kaiser_beta:f64, cutoff:f64) -> SincKernel {
+		let taps:usize = taps.clamp(2, MAX_SINC_TAPS);
+		let half_width:f64 = (taps as f64 / 2.0) / cutoff;
+		let table_resolution:f64 = SINC_TABLE_RESOLUTION as f64 * cutoff.min(1.0);
+		let table_size:usize = taps / 2 * SINC_TABLE_RESOLUTION + 2;
+		let kaiser_scale:f64 = 1.0 / bessel_i0(kaiser_beta);
+		SincKernel {
+			table: (0..table_size).map(|table_index| {
+				let offset:f64 = table_index as f64 / table_resolution;
+				if offset >= half_width {
+					0.0
+				} else {
+					let window_position:f64 = offset / half_width;
+					cutoff * sinc(cutoff * offset) * bessel_i0(kaiser_beta * (1.0 - window_position * window_position).sqrt()) * kaiser_scale
+				}
+			}).collect(),
+			table_resolution,
+			cutoff,
+			half_width
+		}
+	}
+
+	/// The amount of input frames the kernel reaches on each side.
+	pub fn reach(&self) -> usize {
+		self.half_width.ceil() as usize
+	}
+
+	/// Get the weight for an input frame at the given offset from the interpolated position.
+	pub fn weight(&self, offset:f64) -> f64 {
+		let table_position:f64 = offset.abs() * self.table_resolution;
+		let table_index:usize = table_position as usize;
+		if table_index + 1 >= self.table.len() {
+			return 0.0;
+		}
+		if table_position == table_index as f64 {
+			return self.table[table_index];
+		}
+		let fraction:f64 = table_position - table_index as f64;
+		self.table[table_index] * (1.0 - fraction) + self.table[table_index + 1] * fraction
+	}
+}
+
+
+
+/// Resample interleaved data from one sample rate to another. The output always has the exact amount of frames for the rate ratio, rounded to the nearest frame. Windowed sinc conversions between rates with a simple ratio use a precomputed polyphase filter bank.
+pub(crate) fn resample_interleaved(data:&[f32], channel_count:usize, source_sample_rate:u32, target_sample_rate:u32, quality:ResampleQuality) -> Vec<f32> {
+	let source_frame_count:usize = data.len() / channel_count;
+	if source_sample_rate == target_sample_rate || source_frame_count == 0 {
+		return data.to_vec();
+	}
+	if let ResampleQuality::WindowedSinc { taps, kaiser_beta } = quality && let Some(filter) = PolyphaseFilter::new(source_sample_rate, target_sample_rate, taps, kaiser_beta) {
+		return filter.resample_interleaved(data, channel_count);
+	}
+	let target_frame_count:usize = resampled_frame_count(source_frame_count, source_sample_rate, target_sample_rate);
+	let source_frames_per_target_frame:f64 = source_sample_rate as f64 / target_sample_rate as f64;
+	let interpolator:Interpolator = Interpolator::new(quality, target_sample_rate as f64 / source_sample_rate as f64);
+
+	let mut output:Vec<f32> = vec![0.0; target_frame_count * channel_count];
+	let mut weights:Vec<(isize, f64)> = Vec::new();
+	for (target_frame_index, target_frame) in output.chunks_mut(channel_count).enumerate() {
+		interpolator.interpolate_frame(data, channel_count, target_frame_index as f64 * source_frames_per_target_frame, target_frame, &mut weights);
+	}
+	output
+}
+
+/// Get a sample from interleaved data. Frames outside of the data are extrapolated by point reflection around the edge frames, which keeps constant and linear signals intact.
+pub(crate) fn reflected_sample(data:&[f32], channel_count:usize, frame_index:isize, channel_index:usize) -> f64 {
+	let last_frame_index:isize = (data.len() / channel_count) as isize - 1;
+	let sample_at = |frame_index:isize| data[frame_index.clamp(0, last_frame_index) as usize * channel_count + channel_index] as f64;
+	if frame_index < 0 {
+		2.0 * sample_at(0) - sample_at(-frame_index)
+	} else if frame_index > last_frame_index {
+		2.0 * sample_at(last_frame_index) - sample_at(2 * last_frame_index - frame_index)
+	} else {
+		sample_at(frame_index)
+	}
+}
+
+/// Get the amount of frames a buffer has after resampling, rounded to the nearest frame.
+pub(crate) fn resampled_frame_count(source_frame_count:usize, source_sample_rate:u32, target_sample_rate:u32) -> usize {
+	((source_frame_count as u128 * target_sample_rate as u128 * 2 + source_sample_rate as u128) / (source_sample_rate as u128 * 2)) as usize
+}
+
+/// Normalized sinc function.
+fn sinc(value:f64) -> f64 {
+	if value == 0.0 {
+		1.0
+	} else {
+		(PI * value).sin() / (PI * value)
+	}
+}
+
+/// Zeroth order modified Bessel function of the first kind.
+fn bessel_i0(value:f64) -> f64 {
+	let half_value:f64 = value / 2.0;
+	let mut term:f64 = 1.0;
+	let mut sum:f64 = 1.0;
+	let mut order:f64 = 1.0;
+	while term > sum * 1e-12 {
+		term *= (half_value / order) * (half_value / order);
+		sum += term;
+		order += 1.0;
+	}
+	sum
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, ResampleQuality, test_helpers::sine_wave };
+
+
+
+	const QUALITIES:[ResampleQuality; 4] = [
+		ResampleQuality::Nearest,
+		ResampleQuality::Linear,
+		ResampleQuality::Cubic,
+		ResampleQuality::WindowedSinc { taps: 32, kaiser_beta: 8.6 }
+	];
+
+
+
+	fn interior_rms(buffer:&AudioBuffer) -> f32 {
+		let margin:usize = buffer.frame_count() / 10;
+		buffer.view_frames(margin..buffer.frame_count() - margin).rms()
+	}
+
+
+
+	#[test]
+	fn exact_output_length() {
+		for quality in QUALITIES {
+			assert_eq!(sine_wave(440.0, 44100, 2, 44100).resampled_with_quality(2, 48000, quality).frame_count(), 48000);
+			assert_eq!(sine_wave(440.0, 1001, 1, 48000).resampled_with_quality(1, 44100, quality).frame_count(), 920);
+			assert_eq!(sine_wave(440.0, 1000, 1, 8000).resampled_with_quality(1, 22050, quality).frame_count(), 2756);
+		}
+	}
+
+	#[test]
+	fn preserves_low_frequencies() {
+		let original:AudioBuffer = sine_wave(100.0, 4800, 2, 48000);
+		let expected:AudioBuffer = sine_wave(100.0, 4410, 2, 44100);
+		for quality in [ResampleQuality::Cubic, ResampleQuality::default()] {
+			original.clone().resampled_with_quality(2, 44100, quality).assert_similar(&expected, 0.001);
+		}
+		original.clone().resampled_with_quality(2, 44100, ResampleQuality::Linear).assert_similar(&expected, 0.01);
+	}
+
+	#[test]
+	fn nearest_duplicates_frames() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.1, 0.2, 0.3, 0.4], 1, 1000);
+		let resampled:AudioBuffer = original.resampled_with_quality(1, 2000, ResampleQuality::Nearest);
+		assert_eq!(resampled.data, vec![0.1, 0.2, 0.2, 0.3, 0.3, 0.4, 0.4, 0.4]);
+	}
+
+	#[test]
+	fn sinc_removes_aliasing_frequencies() {
+		let original:AudioBuffer = sine_wave(12000.0, 4800, 1, 48000);
+		assert!(interior_rms(&original.clone().resampled_with_quality(1, 16000, ResampleQuality::Nearest)) > 0.5);
+		assert!(interior_rms(&original.resampled_with_quality(1, 16000, ResampleQuality::default())) < 0.01);
+	}
+
+	#[test]
+	fn keeps_channels_separate() {
+		let original:AudioBuffer = AudioBuffer::new((0..1000).flat_map(|_| [0.5, -0.25]).collect(), 2, 1000);
+		let resampled:AudioBuffer = original.resampled(2, 3000);
+		assert!(resampled.frames().all(|frame| (frame[0] - 0.5).abs() < 0.0001 && (frame[1] + 0.25).abs() < 0.0001));
+	}
+}
@@ -1,14 +1,80 @@
 mod core;
+mod core_u;
+mod error;
+mod fft;
+mod fft_u;
+mod channel_layout;
+mod channel_layout_u;
+mod channel_mixing;
+mod channel_mixing_u;
+mod concatenation;
+mod concatenation_u;
+mod fade;
+mod fade_u;
+mod frames;
+mod frames_u;
+mod view;
+mod view_u;
+mod analysis;
+mod analysis_u;
 mod wav_parser;
+mod timing;
+mod timing_u;
 mod data_modification;
 mod data_modification_u;
+mod edit_session;
+mod edit_session_u;
+mod interpolation;
+mod interpolation_u;
+mod looping;
+mod looping_u;
+mod pitch_shifting;
+mod pitch_shifting_u;
+mod polyphase;
+mod polyphase_u;
+mod resampler;
+mod resampler_u;
 mod resampling;
 mod resampling_u;
+mod silence;
+mod silence_u;
+mod time_stretching;
+mod time_stretching_u;
+mod timeline;
+mod timeline_u;
 mod trimming;
 mod trimming_u;
+mod varispeed;
+mod varispeed_u;
+mod null_test;
+mod null_test_u;
+mod overflow;
+mod overflow_u;
+mod mixer;
+mod mixer_u;
 mod mixing;
 mod mixing_u;
 mod effect;
+#[cfg(test)]
+mod test_helpers;
 pub use effect::*;
 
-pub use core::*;
\ No newline at end of file
+pub use core::*;
+pub use error::*;
+pub use fade::{ FadeCurve, GainEnvelope };
+pub use channel_layout::*;
+pub use channel_mixing::*;
+pub use concatenation::*;
+pub use edit_session::*;
+pub use interpolation::ResampleQuality;
+pub use mixer::*;
+pub use mixing::{ AudioBufferAddition, PlacedAddition, RawAddition };
+pub use null_test::*;
+pub use overflow::*;
+pub use resampler::*;
+pub use silence::*;
+pub use time_stretching::TimeStretchMode;
+pub use timeline::*;
+pub use trimming::{ TrimDetection, TrimSettings };
+pub use varispeed::*;
+pub use view::*;
\ No newline at end of file
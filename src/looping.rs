@@ -0,0 +1,110 @@
+use crate::{ AudioBuffer, FadeCurve };
+use std::{ ops::Range, time::Duration };
+
+
+
+impl AudioBuffer {
+
+	/* LOOP RANGE METHODS */
+
+	/// Get the range of frames that should be looped on playback, if any. Stored in the sampler chunk of WAV files.
+	pub fn loop_range(&self) -> Option<Range<usize>> {
+		self.loop_range.clone()
+	}
+
+	/// Return the audio-buffer with the given range of frames marked as loop.
+	pub fn with_loop_range(mut self, range:Range<usize>) -> Self {
+		self.set_loop_range(Some(range));
+		self
+	}
+
+	/// Set or clear the range of frames that should be looped on playback.
+	pub fn set_loop_range(&mut self, range:Option<Range<usize>>) {
+		self.loop_range = range;
+	}
+
+	/// Move the loop range after a range of frames was replaced by the given amount of frames. Loop edges within the replaced frames move to its edges, a loop that no longer contains any frames is cleared.
+	pub(crate) fn replace_loop_frames(&mut self, replaced:Range<usize>, inserted_frame_count:usize) {
+		if let Some(loop_range) = &self.loop_range {
+			let move_edge = |frame_index:usize, is_start:bool| {
+				if frame_index >= replaced.end {
+					frame_index - replaced.end + replaced.start + inserted_frame_count
+				} else if frame_index <= replaced.start {
+					frame_index
+				} else if is_start {
+					replaced.start + inserted_frame_count
+				} else {
+					replaced.start
+				}
+			};
+			let moved:Range<usize> = move_edge(loop_range.start, true)..move_edge(loop_range.end, false);
+			self.loop_range = Some(moved).filter(|range| !range.is_empty());
+		}
+	}
+
+	/// Scale the loop range after the audio was stretched from the given amount of frames to its current length.
+	pub(crate) fn scale_loop_range(&mut self, source_frame_count:usize) {
+		let frame_count:usize = self.frame_count();
+		if let Some(loop_range) = &self.loop_range {
+			let scale = |frame_index:usize| (frame_index as f64 * frame_count as f64 / source_frame_count.max(1) as f64).round() as usize;
+			let scaled:Range<usize> = scale(loop_range.start)..scale(loop_range.end);
+			self.loop_range = Some(scaled).filter(|range| !range.is_empty());
+		}
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Return the audio-buffer reversed.
+	pub fn reversed(mut self) -> Self {
+		self.reverse();
+		self
+	}
+
+	/// Reverse the order of the frames. The channels within each frame keep their order, the loop range is mirrored.
+	pub fn reverse(&mut self) {
+		let frame_count:usize = self.frame_count();
+		self.data = self.frames().rev().flatten().copied().collect();
+		if let Some(loop_range) = &mut self.loop_range {
+			*loop_range = frame_count.saturating_sub(loop_range.end)..frame_count.saturating_sub(loop_range.start);
+		}
+	}
+
+	/// Return the audio-buffer repeated the given amount of times.
+	pub fn repeated(mut self, count:usize) -> Self {
+		self.repeat(count);
+		self
+	}
+
+	/// Repeat the audio the given amount of times, a count of 1 keeps the audio as is and 0 leaves it empty. The loop range stays on the first repetition.
+	pub fn repeat(&mut self, count:usize) {
+		self.data = self.data.repeat(count);
+		if count == 0 {
+			self.loop_range = None;
+		}
+	}
+
+	/// Return the audio-buffer as a seamless loop.
+	pub fn looped(mut self, crossfade:Duration, curve:FadeCurve) -> Self {
+		self.make_loop(crossfade, curve);
+		self
+	}
+
+	/// Turn the audio into a seamless loop. The tail of the given duration is crossfaded into the head, so the end flows into the start without a click. The buffer becomes shorter by the crossfade, which is limited to half the duration, and the whole buffer is marked as loop. An empty buffer is not marked.
+	pub fn make_loop(&mut self, crossfade:Duration, curve:FadeCurve) {
+		let channel_count:usize = self.channel_count;
+		let frame_count:usize = self.frame_count();
+		let crossfade_frame_count:usize = self.duration_to_frames(crossfade).min(frame_count / 2);
+		let tail:Vec<f32> = self.data.split_off((frame_count - crossfade_frame_count) * channel_count);
+		for (frame_index, (head_frame, tail_frame)) in self.frames_mut().zip(tail.chunks_exact(channel_count)).enumerate() {
+			let progress:f32 = frame_index as f32 / crossfade_frame_count as f32;
+			let head_gain:f32 = curve.gain(progress);
+			let tail_gain:f32 = curve.gain(1.0 - progress);
+			for (head_sample, tail_sample) in head_frame.iter_mut().zip(tail_frame) {
+				*head_sample = *head_sample * head_gain + tail_sample * tail_gain;
+			}
+		}
+		self.loop_range = Some(0..self.frame_count()).filter(|range| !range.is_empty());
+	}
+}
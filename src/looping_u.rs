@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, ConcatenationSettings, FadeCurve, TimeStretchMode, TrimDetection, TrimSettings };
+	use std::time::Duration;
+
+
+
+	fn stereo_counting_buffer(frame_count:usize) -> AudioBuffer {
+		AudioBuffer::new((0..frame_count * 2).map(|index| index as f32).collect(), 2, 1000)
+	}
+
+	#[test]
+	fn loop_range() {
+		let mut buffer:AudioBuffer = stereo_counting_buffer(10);
+		assert_eq!(buffer.loop_range(), None);
+		buffer = buffer.with_loop_range(2..6);
+		assert_eq!(buffer.loop_range(), Some(2..6));
+		assert_eq!(buffer.slice_frames(0..5).loop_range(), None);
+		assert_eq!(buffer.clone().resampled(2, 2000).loop_range(), Some(4..12));
+		buffer.set_loop_range(None);
+		assert_eq!(buffer.loop_range(), None);
+	}
+
+	#[test]
+	fn reverse() {
+		let reversed:AudioBuffer = stereo_counting_buffer(3).with_loop_range(0..1).reversed();
+		assert_eq!(reversed.data, vec![4.0, 5.0, 2.0, 3.0, 0.0, 1.0]);
+		assert_eq!(reversed.loop_range(), Some(2..3));
+		assert_eq!(reversed.reversed(), stereo_counting_buffer(3).with_loop_range(0..1));
+	}
+
+	#[test]
+	fn repeat() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![1.0, 2.0], 1, 1000);
+		assert_eq!(buffer.clone().repeated(3).data, vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+		assert_eq!(buffer.clone().repeated(1), buffer);
+		assert_eq!(buffer.repeated(0).frame_count(), 0);
+	}
+
+	#[test]
+	fn make_loop() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..10).map(|index| index as f32).collect(), 1, 1000);
+		let looped:AudioBuffer = buffer.looped(Duration::from_millis(4), FadeCurve::Linear);
+		assert_eq!(looped.frame_count(), 6);
+		assert_eq!(looped.loop_range(), Some(0..6));
+
+		// The start continues from the end of the remaining audio and blends into the original head.
+		looped.assert_similar(&AudioBuffer::new(vec![6.0, 0.25 + 7.0 * 0.75, 1.0 + 8.0 * 0.5, 2.25 + 9.0 * 0.25, 4.0, 5.0], 1, 1000), 0.0001);
+		assert_eq!(AudioBuffer::new(Vec::new(), 1, 1000).looped(Duration::from_millis(4), FadeCurve::Linear).loop_range(), None);
+	}
+
+	#[test]
+	fn make_loop_is_seamless() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..1000).flat_map(|index| { let sample:f32 = (index as f32 * 0.0731).sin(); [sample, -sample] }).collect(), 2, 1000);
+		let looped:AudioBuffer = buffer.looped(Duration::from_millis(100), FadeCurve::EqualPower);
+		let repeated:AudioBuffer = looped.repeated(2);
+		let join:usize = repeated.frame_count() / 2;
+		let step:f32 = (repeated.frame(join).unwrap()[0] - repeated.frame(join - 1).unwrap()[0]).abs();
+		assert!(step < 0.1);
+
+		// Crossfading more than half the buffer is limited.
+		assert_eq!(AudioBuffer::new(vec![1.0; 10], 1, 1000).looped(Duration::from_secs(1), FadeCurve::Linear).frame_count(), 5);
+	}
+
+	#[test]
+	fn loop_range_follows_padding() {
+		let padded:AudioBuffer = stereo_counting_buffer(10).with_loop_range(2..6).padded_frames(3, 4);
+		assert_eq!(padded.loop_range(), Some(5..9));
+		assert_eq!(padded.frame(5), stereo_counting_buffer(10).frame(2));
+	}
+
+	#[test]
+	fn loop_range_follows_frame_edits() {
+		let looped:AudioBuffer = stereo_counting_buffer(10).with_loop_range(4..8);
+		assert_eq!(looped.clone().inserted_at_frame(2, vec![0.0; 6]).loop_range(), Some(7..11));
+		assert_eq!(looped.clone().inserted_at_frame(6, vec![0.0; 6]).loop_range(), Some(4..11));
+		assert_eq!(looped.clone().inserted_at_frame(9, vec![0.0; 6]).loop_range(), Some(4..8));
+		assert_eq!(looped.clone().replaced_frames(6..10, vec![0.0; 2]).loop_range(), Some(4..6));
+		assert_eq!(looped.clone().replaced_frames(2..6, Vec::<f32>::new()).loop_range(), Some(2..4));
+
+		let mut drained:AudioBuffer = looped.clone();
+		drained.drain_frames(0..5);
+		assert_eq!(drained.loop_range(), Some(0..3));
+		drained.drain_frames(0..3);
+		assert_eq!(drained.loop_range(), None);
+
+		let mut split:AudioBuffer = looped.clone();
+		let tail:AudioBuffer = split.split_off_frames(6);
+		assert_eq!(split.loop_range(), Some(4..6));
+		assert_eq!(tail.loop_range(), None);
+	}
+
+	#[test]
+	fn loop_range_follows_trimming() {
+		let data:Vec<f32> = [vec![0.0; 4], vec![0.5; 6], vec![0.0; 4]].concat();
+		let mut trimmed:AudioBuffer = AudioBuffer::new(data.clone(), 1, 1000).with_loop_range(2..8);
+		trimmed.trim(0.1);
+		assert_eq!(trimmed.frame_count(), 6);
+		assert_eq!(trimmed.loop_range(), Some(0..4));
+
+		let settings:TrimSettings = TrimSettings::new(-20.0).with_detection(TrimDetection::Peak, Duration::ZERO).with_min_duration(Duration::from_millis(1));
+		let trimmed:AudioBuffer = AudioBuffer::new(data.clone(), 1, 1000).with_loop_range(5..12).trimmed_with(&settings);
+		assert_eq!(trimmed.loop_range(), Some(1..6));
+		assert_eq!(AudioBuffer::new(vec![0.0; 10], 1, 1000).with_loop_range(2..8).trimmed_with(&settings).loop_range(), None);
+	}
+
+	#[test]
+	fn loop_range_follows_time_stretch() {
+		let stretched:AudioBuffer = AudioBuffer::new(vec![0.0; 1000], 1, 1000).with_loop_range(100..400).time_stretched(2.0, TimeStretchMode::Wsola);
+		assert_eq!(stretched.frame_count(), 2000);
+		assert_eq!(stretched.loop_range(), Some(200..800));
+	}
+
+	#[test]
+	fn loop_range_follows_append() {
+		let appended:AudioBuffer = stereo_counting_buffer(10).with_loop_range(4..10).appended(stereo_counting_buffer(5), &ConcatenationSettings::new());
+		assert_eq!(appended.loop_range(), Some(4..10));
+
+		let aligned:AudioBuffer = AudioBuffer::new(vec![0.5, 0.5, 0.5, -0.5, 0.5, 0.5], 1, 1000).with_loop_range(1..6).appended(vec![0.5; 4], &ConcatenationSettings::new().with_zero_crossing_alignment(Duration::from_millis(5)));
+		assert_eq!(aligned.loop_range(), Some(1..4));
+	}
+}
@@ -0,0 +1,277 @@
+use crate::{ AudioBuffer, AudioEffect, ChannelLayout, OverflowStrategy, analysis::decibels_to_gain };
+use std::f32::consts::FRAC_PI_2;
+
+
+
+/// The way a mono source is spread over the stereo master when panned. Sources with multiple channels are always panned as a balance, keeping unity gain in the center.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum PanLaw {
+	/// Keeps both channels at full level in the center and only lowers the opposite channel when panning.
+	Balance,
+
+	/// Linear gains, -6 dB per channel in the center.
+	Linear,
+
+	/// Sine and cosine gains, -3 dB per channel in the center. Keeps the perceived loudness constant.
+	#[default]
+	ConstantPower,
+
+	/// Halfway between linear and constant power, -4.5 dB per channel in the center.
+	Compromise
+}
+impl PanLaw {
+
+	/// Get the left and right gain for a pan position from -1.0 (left) to 1.0 (right).
+	pub fn gains(&self, pan:f32) -> (f32, f32) {
+		let position:f32 = (pan.clamp(-1.0, 1.0) + 1.0) / 2.0;
+		match self {
+			PanLaw::Balance => ((2.0 - 2.0 * position).min(1.0), (2.0 * position).min(1.0)),
+			PanLaw::Linear => (1.0 - position, position),
+			PanLaw::ConstantPower => ((position * FRAC_PI_2).cos(), (position * FRAC_PI_2).sin()),
+			PanLaw::Compromise => (((1.0 - position) * (position * FRAC_PI_2).cos()).sqrt(), (position * (position * FRAC_PI_2).sin()).sqrt())
+		}
+	}
+}
+
+
+
+pub struct MixerTrack {
+	name:String,
+	source:AudioBuffer,
+	gain_db:f32,
+	pan:f32,
+	pan_law:PanLaw,
+	muted:bool,
+	soloed:bool,
+	effects:Vec<Box<dyn AudioEffect>>
+}
+impl MixerTrack {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new track at unity gain, panned to the center.
+	pub fn new(name:&str, source:AudioBuffer) -> MixerTrack {
+		MixerTrack {
+			name: name.to_string(),
+			source,
+			gain_db: 0.0,
+			pan: 0.0,
+			pan_law: PanLaw::default(),
+			muted: false,
+			soloed: false,
+			effects: Vec::new()
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the name of the track.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Get the source audio of the track.
+	pub fn source(&self) -> &AudioBuffer {
+		&self.source
+	}
+
+	/// Get the gain of the track in decibels.
+	pub fn gain_db(&self) -> f32 {
+		self.gain_db
+	}
+
+	/// Get the pan position of the track, from -1.0 (left) to 1.0 (right).
+	pub fn pan(&self) -> f32 {
+		self.pan
+	}
+
+	/// Get the pan law of the track.
+	pub fn pan_law(&self) -> PanLaw {
+		self.pan_law
+	}
+
+	/// Whether the track is muted.
+	pub fn is_muted(&self) -> bool {
+		self.muted
+	}
+
+	/// Whether the track is soloed.
+	pub fn is_soloed(&self) -> bool {
+		self.soloed
+	}
+
+	/// Get the effect chain of the track.
+	pub fn effects_mut(&mut self) -> &mut Vec<Box<dyn AudioEffect>> {
+		&mut self.effects
+	}
+
+
+
+	/* PROPERTY SETTER METHODS */
+
+	/// Set the source audio of the track.
+	pub fn set_source(&mut self, source:AudioBuffer) -> &mut Self {
+		self.source = source;
+		self
+	}
+
+	/// Set the gain of the track in decibels.
+	pub fn set_gain_db(&mut self, gain_db:f32) -> &mut Self {
+		self.gain_db = gain_db;
+		self
+	}
+
+	/// Set the pan position of the track, from -1.0 (left) to 1.0 (right). Only applies to stereo renders. Mono sources use the pan law, other sources are balanced.
+	pub fn set_pan(&mut self, pan:f32) -> &mut Self {
+		self.pan = pan.clamp(-1.0, 1.0);
+		self
+	}
+
+	/// Set the pan law of the track. Only applies to mono sources.
+	pub fn set_pan_law(&mut self, pan_law:PanLaw) -> &mut Self {
+		self.pan_law = pan_law;
+		self
+	}
+
+	/// Set whether the track is muted. A muted track is silent, even when it is soloed.
+	pub fn set_muted(&mut self, muted:bool) -> &mut Self {
+		self.muted = muted;
+		self
+	}
+
+	/// Set whether the track is soloed. When any track that is not muted is soloed, only soloed tracks are rendered.
+	pub fn set_soloed(&mut self, soloed:bool) -> &mut Self {
+		self.soloed = soloed;
+		self
+	}
+
+	/// Add an effect to the end of the effect chain of the track.
+	pub fn add_effect<T:AudioEffect + 'static>(&mut self, effect:T) -> &mut Self {
+		self.effects.push(Box::new(effect));
+		self
+	}
+
+
+
+	/* RENDERING METHODS */
+
+	/// Render the track at the given channel count and sample rate. The source is resampled, then passed through the effect chain from its initial state, then the gain and pan are applied.
+	fn render(&mut self, channel_count:usize, sample_rate:u32) -> AudioBuffer {
+		let mono_to_stereo:bool = self.source.channel_count() == 1 && channel_count == 2;
+		let mut buffer:AudioBuffer = if mono_to_stereo {
+
+			// Copy mono sources to both channels at full level, the pan law takes care of their level in the center.
+			let mono:AudioBuffer = self.source.clone().resampled(1, sample_rate);
+			let mut stereo:AudioBuffer = AudioBuffer::new(mono.data.iter().flat_map(|sample| [*sample, *sample]).collect(), 2, sample_rate);
+			stereo.channel_layout = mono.channel_layout.and(ChannelLayout::default_for_channel_count(2));
+			stereo
+		} else {
+			self.source.clone().resampled(channel_count, sample_rate)
+		};
+		for effect in &mut self.effects {
+			effect.reset();
+			effect.apply_to(&mut buffer);
+		}
+
+		let gain:f32 = decibels_to_gain(self.gain_db);
+		let channel_gains:Vec<f32> = if channel_count == 2 {
+			let pan_law:PanLaw = if mono_to_stereo { self.pan_law } else { PanLaw::Balance };
+			let (left_gain, right_gain) = pan_law.gains(self.pan);
+			vec![left_gain * gain, right_gain * gain]
+		} else {
+			vec![gain; channel_count]
+		};
+		for frame in buffer.frames_mut() {
+			frame.iter_mut().zip(&channel_gains).for_each(|(sample, gain)| *sample *= gain);
+		}
+		buffer
+	}
+}
+
+
+
+pub struct Mixer {
+	tracks:Vec<MixerTrack>,
+	overflow:OverflowStrategy
+}
+impl Mixer {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new mixer without tracks. The master is hard-clipped by default.
+	pub fn new() -> Mixer {
+		Mixer {
+			tracks: Vec::new(),
+			overflow: OverflowStrategy::default()
+		}
+	}
+
+	/// Return the mixer with the given overflow strategy for the master.
+	pub fn with_overflow(mut self, overflow:OverflowStrategy) -> Self {
+		self.overflow = overflow;
+		self
+	}
+
+
+
+	/* TRACK METHODS */
+
+	/// Add a track with the given name and source. Replaces any track with the same name. Returns the track so it can be configured.
+	pub fn add_track(&mut self, name:&str, source:AudioBuffer) -> &mut MixerTrack {
+		self.tracks.retain(|track| track.name != name);
+		self.tracks.push(MixerTrack::new(name, source));
+		self.tracks.last_mut().unwrap()
+	}
+
+	/// Remove the track with the given name.
+	pub fn remove_track(&mut self, name:&str) -> Option<MixerTrack> {
+		self.tracks.iter().position(|track| track.name == name).map(|index| self.tracks.remove(index))
+	}
+
+	/// Get the track with the given name.
+	pub fn track(&self, name:&str) -> Option<&MixerTrack> {
+		self.tracks.iter().find(|track| track.name == name)
+	}
+
+	/// Get the track with the given name mutably.
+	pub fn track_mut(&mut self, name:&str) -> Option<&mut MixerTrack> {
+		self.tracks.iter_mut().find(|track| track.name == name)
+	}
+
+	/// Get all tracks in the order they were added.
+	pub fn tracks(&self) -> &[MixerTrack] {
+		&self.tracks
+	}
+
+	/// Get the overflow strategy of the master.
+	pub fn overflow(&self) -> OverflowStrategy {
+		self.overflow
+	}
+
+	/// Set the overflow strategy of the master.
+	pub fn set_overflow(&mut self, overflow:OverflowStrategy) {
+		self.overflow = overflow;
+	}
+
+
+
+	/* RENDERING METHODS */
+
+	/// Render all audible tracks into a master buffer with the given channel count and sample rate. Stateful effects are reset before every render, so rendering twice gives the same result. A track that is both muted and soloed stays silent and does not silence the other tracks. The master keeps the channel layout of the tracks if they all share one.
+	pub fn render(&mut self, channel_count:usize, sample_rate:u32) -> AudioBuffer {
+		let any_soloed:bool = self.tracks.iter().any(|track| track.soloed && !track.muted);
+		let rendered_tracks:Vec<AudioBuffer> = self.tracks.iter_mut().filter(|track| !track.muted && (track.soloed || !any_soloed)).map(|track| track.render(channel_count, sample_rate)).collect();
+		let mut master:AudioBuffer = AudioBuffer::new(Vec::new(), channel_count, sample_rate);
+		if let Some(first_track) = rendered_tracks.first() && rendered_tracks.iter().all(|track| track.channel_layout == first_track.channel_layout) {
+			master.channel_layout = first_track.channel_layout.clone();
+		}
+		master.combined_with_overflow(rendered_tracks, self.overflow)
+	}
+}
+impl Default for Mixer {
+	fn default() -> Self {
+		Mixer::new()
+	}
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod test {
+	use std::f32::consts::FRAC_1_SQRT_2;
+	use crate::{ AudioAmplifier, AudioBuffer, ChannelLayout, FadeCurve, Fader, Mixer, OverflowStrategy, PanLaw };
+	use std::time::Duration;
+
+
+
+	#[test]
+	fn pan_law_gains() {
+		assert_eq!(PanLaw::Balance.gains(0.0), (1.0, 1.0));
+		assert_eq!(PanLaw::Balance.gains(0.5), (0.5, 1.0));
+		assert_eq!(PanLaw::Linear.gains(0.0), (0.5, 0.5));
+		let (left, right) = PanLaw::ConstantPower.gains(0.0);
+		assert!((left - FRAC_1_SQRT_2).abs() < 0.0001 && (right - FRAC_1_SQRT_2).abs() < 0.0001);
+		let (left, right) = PanLaw::Compromise.gains(0.0);
+		assert!((left - 0.5946).abs() < 0.0001 && (right - 0.5946).abs() < 0.0001);
+		for law in [PanLaw::Balance, PanLaw::Linear, PanLaw::ConstantPower, PanLaw::Compromise] {
+			let (left, right) = law.gains(-1.0);
+			assert!((left - 1.0).abs() < 0.0001 && right.abs() < 0.0001);
+		}
+	}
+
+	#[test]
+	fn mixer_sums_tracks() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.1; 10], 1, 100));
+		mixer.add_track("b", AudioBuffer::new(vec![0.2; 20], 1, 100)).set_gain_db(-6.0206);
+		let master:AudioBuffer = mixer.render(1, 100);
+		master.assert_similar(&AudioBuffer::new([vec![0.2; 10], vec![0.1; 10]].concat(), 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn mixer_resamples_and_pans() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("mono", AudioBuffer::new(vec![0.5; 50], 1, 50)).set_pan(-1.0).set_pan_law(PanLaw::Linear);
+		let master:AudioBuffer = mixer.render(2, 100);
+		assert_eq!(master.channel_count(), 2);
+		assert_eq!(master.frame_count(), 100);
+		for frame in master.frames() {
+			assert!((frame[0] - 0.5).abs() < 0.0001);
+			assert_eq!(frame[1], 0.0);
+		}
+	}
+
+	#[test]
+	fn mixer_pans_stereo_as_balance() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("stereo", AudioBuffer::new(vec![0.5; 20], 2, 100));
+		mixer.render(2, 100).assert_similar(&AudioBuffer::new(vec![0.5; 20], 2, 100), 0.0001);
+
+		mixer.track_mut("stereo").unwrap().set_pan(0.5);
+		for frame in mixer.render(2, 100).frames() {
+			assert!((frame[0] - 0.25).abs() < 0.0001 && (frame[1] - 0.5).abs() < 0.0001);
+		}
+	}
+
+	#[test]
+	fn mixer_applies_pan_law_to_mono_once() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("mono", AudioBuffer::new(vec![0.5; 10], 1, 100).with_channel_layout(ChannelLayout::Mono));
+		for frame in mixer.render(2, 100).frames() {
+			assert!((frame[0] - 0.5 * FRAC_1_SQRT_2).abs() < 0.0001 && (frame[1] - 0.5 * FRAC_1_SQRT_2).abs() < 0.0001);
+		}
+	}
+
+	#[test]
+	fn mixer_mute_and_solo() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.1; 4], 1, 100));
+		mixer.add_track("b", AudioBuffer::new(vec![0.2; 4], 1, 100));
+		mixer.add_track("c", AudioBuffer::new(vec![0.4; 4], 1, 100));
+		mixer.track_mut("b").unwrap().set_muted(true);
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![0.5; 4], 1, 100), 0.0001);
+
+		mixer.track_mut("c").unwrap().set_soloed(true);
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![0.4; 4], 1, 100), 0.0001);
+
+		mixer.track_mut("b").unwrap().set_soloed(true);
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![0.4; 4], 1, 100), 0.0001);
+
+		assert!(mixer.remove_track("c").is_some());
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![0.1; 4], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn mixer_applies_effects() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.1; 4], 1, 100)).add_effect(AudioAmplifier::new(3.0));
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![0.3; 4], 1, 100), 0.0001);
+		assert_eq!(mixer.track_mut("a").unwrap().effects_mut().len(), 1);
+	}
+
+	#[test]
+	fn mixer_renders_reproducibly() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.5; 4], 1, 100)).add_effect(Fader::fade_in(Duration::from_millis(40), FadeCurve::Linear));
+		let first_render:AudioBuffer = mixer.render(1, 100);
+		first_render.assert_similar(&AudioBuffer::new(vec![0.0, 0.125, 0.25, 0.375], 1, 100), 0.0001);
+		assert_eq!(mixer.render(1, 100), first_render);
+	}
+
+	#[test]
+	fn mixer_keeps_channel_layout() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.1; 8], 2, 100).with_channel_layout(ChannelLayout::Stereo));
+		assert_eq!(mixer.render(2, 100).channel_layout(), Some(&ChannelLayout::Stereo));
+		mixer.add_track("b", AudioBuffer::new(vec![0.1; 4], 1, 100).with_channel_layout(ChannelLayout::Mono));
+		assert_eq!(mixer.render(2, 100).channel_layout(), Some(&ChannelLayout::Stereo));
+		mixer.add_track("c", AudioBuffer::new(vec![0.1; 8], 2, 100));
+		assert_eq!(mixer.render(2, 100).channel_layout(), None);
+	}
+
+	#[test]
+	fn mixer_overflow() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.8; 4], 1, 100));
+		mixer.add_track("b", AudioBuffer::new(vec![0.8; 4], 1, 100));
+		assert_eq!(mixer.render(1, 100).data, vec![1.0; 4]);
+		mixer.set_overflow(OverflowStrategy::None);
+		mixer.render(1, 100).assert_similar(&AudioBuffer::new(vec![1.6; 4], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn mixer_replaces_track_with_same_name() {
+		let mut mixer:Mixer = Mixer::new();
+		mixer.add_track("a", AudioBuffer::new(vec![0.1; 4], 1, 100));
+		mixer.add_track("a", AudioBuffer::new(vec![0.2; 4], 1, 100));
+		assert_eq!(mixer.tracks().len(), 1);
+		assert_eq!(mixer.track("a").unwrap().source().data, vec![0.2; 4]);
+	}
+}
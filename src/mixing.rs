@@ -1,5 +1,5 @@
-use std::ops::{Add, AddAssign};
-use crate::AudioBuffer;
+use std::{ ops::{ Add, AddAssign, Range, Sub, SubAssign }, time::Duration };
+use crate::{ AudioBuffer, AudioBufferError, OverflowStrategy };
 
 
 
@@ -11,27 +11,119 @@ impl AudioBuffer {
 		self
 	}
 	
-	/// Combine the audio with another. Makes the addition overlap the current one. Resamples the addition if it doesn't match the sampling settings as self. Grows self if there is not enough space.
+	/// Return the audio combined with another. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_combined_with<T:AudioBufferAddition>(mut self, addition:T) -> Result<Self, AudioBufferError> {
+		self.try_combine_with(addition)?;
+		Ok(self)
+	}
+
+	/// Combine the audio with another. Returns an error instead of panicking if self or the addition is invalid.
+	pub fn try_combine_with<T:AudioBufferAddition>(&mut self, addition:T) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		let raw_addition:Vec<RawAddition> = addition.try_into_placed_list(self.channel_count, self.sample_rate)?;
+		if let Some(misaligned) = raw_addition.iter().find(|addition| !addition.data.len().is_multiple_of(self.channel_count)) {
+			return Err(AudioBufferError::MisalignedData { sample_count: misaligned.data.len(), channel_count: self.channel_count });
+		}
+		self.combine_raw(raw_addition, OverflowStrategy::default());
+		Ok(())
+	}
+	
+	/// Combine the audio with another. Makes the addition overlap the current one. Resamples the addition if it doesn't match the sampling settings as self. Grows self if there is not enough space. Samples are hard-clipped to the range -1.0 to 1.0.
 	pub fn combine_with<T:AudioBufferAddition>(&mut self, addition:T) {
+		self.combine_with_overflow(addition, OverflowStrategy::default());
+	}
+
+	/// Return the audio combined with another, handling samples outside of the range -1.0 to 1.0 using the given strategy.
+	pub fn combined_with_overflow<T:AudioBufferAddition>(mut self, addition:T, overflow:OverflowStrategy) -> Self {
+		self.combine_with_overflow(addition, overflow);
+		self
+	}
+
+	/// Combine the audio with another, handling samples outside of the range -1.0 to 1.0 using the given strategy. Normalizing scales the whole buffer, other strategies are only applied to the frames the addition reaches.
+	pub fn combine_with_overflow<T:AudioBufferAddition>(&mut self, addition:T, overflow:OverflowStrategy) {
+		let raw_addition:Vec<RawAddition> = addition.into_placed_list(self.channel_count, self.sample_rate);
+		self.combine_raw(raw_addition, overflow);
+	}
+
+	/// Return the audio combined with an addition placed at the given frame offset, multiplied by the given gain. A negative offset trims the start of the addition.
+	pub fn combined_at_frame<T:AudioBufferAddition>(mut self, addition:T, frame_offset:isize, gain:f32) -> Self {
+		self.combine_at_frame(addition, frame_offset, gain);
+		self
+	}
 
-		// Parse addition.
-		let raw_addition:Vec<Vec<f32>> = addition.as_raw_list(self.channel_count, self.sample_rate);
-		let longest_buffer_size:usize = raw_addition.iter().map(|data| data.len()).max().unwrap_or_default();
+	/// Combine the audio with an addition placed at the given frame offset, multiplied by the given gain. A negative offset trims the start of the addition. Grows self if there is not enough space.
+	pub fn combine_at_frame<T:AudioBufferAddition>(&mut self, addition:T, frame_offset:isize, gain:f32) {
+		self.combine_with(PlacedAddition::at_frame(addition, frame_offset).with_gain(gain));
+	}
+
+	/// Return the audio combined with an addition placed at the given offset in seconds, multiplied by the given gain. A negative offset trims the start of the addition.
+	pub fn combined_at_seconds<T:AudioBufferAddition>(mut self, addition:T, seconds:f64, gain:f32) -> Self {
+		self.combine_at_seconds(addition, seconds, gain);
+		self
+	}
+
+	/// Combine the audio with an addition placed at the given offset in seconds, multiplied by the given gain. A negative offset trims the start of the addition. Grows self if there is not enough space.
+	pub fn combine_at_seconds<T:AudioBufferAddition>(&mut self, addition:T, seconds:f64, gain:f32) {
+		self.combine_with(PlacedAddition::at_seconds(addition, seconds).with_gain(gain));
+	}
+
+	/// Return the audio with the addition subtracted.
+	pub fn subtracted<T:AudioBufferAddition>(mut self, addition:T) -> Self {
+		self.subtract(addition);
+		self
+	}
+
+	/// Subtract the addition from the audio. Resamples the addition if it doesn't match the sampling settings as self. Grows self if there is not enough space. The result is not clipped, so residuals of null-tests stay intact.
+	pub fn subtract<T:AudioBufferAddition>(&mut self, addition:T) {
+		let raw_addition:Vec<RawAddition> = addition.into_placed_list(self.channel_count, self.sample_rate).into_iter().map(|addition| addition.placed(0, -1.0)).collect();
+		self.combine_raw(raw_addition, OverflowStrategy::None);
+	}
+
+	/// Combine the audio with a list of raw additions that already match the sampling settings of self.
+	pub(crate) fn combine_raw(&mut self, raw_addition:Vec<RawAddition>, overflow:OverflowStrategy) {
+		let channel_count:usize = self.channel_count;
+
+		// Find the ranges of data the additions reach.
+		let mut modified_ranges:Vec<Range<usize>> = Vec::new();
+		let placements:Vec<(usize, usize)> = raw_addition.iter().map(|addition| {
+			let skipped_sample_count:usize = (addition.frame_offset.min(0).unsigned_abs() * channel_count).min(addition.data.len());
+			let start:usize = addition.frame_offset.max(0) as usize * channel_count;
+			let end:usize = start + addition.data.len() - skipped_sample_count;
+			if end > start {
+				modified_ranges.push(start..end);
+			}
+			(start, skipped_sample_count)
+		}).collect();
+		let modified_end:usize = modified_ranges.iter().map(|range| range.end).max().unwrap_or(0);
 
 		// Grow data to fit largest buffer.
-		if longest_buffer_size > self.data.len() {
-			self.data.extend(vec![0.0; longest_buffer_size - self.data.len()]);
+		if modified_end > self.data.len() {
+			self.data.extend(vec![0.0; modified_end - self.data.len()]);
 		}
 
 		// Overlap the additions with self.
-		for addition_wave in raw_addition {
-			for (source_sample, addition_sample) in self.data[..addition_wave.len()].iter_mut().zip(addition_wave) {
-				*source_sample += addition_sample;
+		for (addition, (start, skipped_sample_count)) in raw_addition.into_iter().zip(placements) {
+			for (source_sample, addition_sample) in self.data.iter_mut().skip(start).zip(&addition.data[skipped_sample_count..]) {
+				*source_sample += addition_sample * addition.gain;
 			}
 		}
 
-		// Make sure the new wave does not exceed limits.
-		self.data[..longest_buffer_size].iter_mut().for_each(|sample| *sample = sample.max(-1.0).min(1.0));
+		// Handle samples that exceed the limits. Normalizing scales the whole buffer to keep its balance, other strategies leave data the additions did not touch as it was.
+		if overflow == OverflowStrategy::Normalize {
+			overflow.apply(&mut self.data, channel_count, self.sample_rate);
+			return;
+		}
+		modified_ranges.sort_by_key(|range| range.start);
+		let mut merged_ranges:Vec<Range<usize>> = Vec::new();
+		for range in modified_ranges {
+			match merged_ranges.last_mut() {
+				Some(last_range) if range.start <= last_range.end => last_range.end = last_range.end.max(range.end),
+				_ => merged_ranges.push(range)
+			}
+		}
+		for range in merged_ranges {
+			overflow.apply(&mut self.data[range], channel_count, self.sample_rate);
+		}
 	}
 }
 impl<T:AudioBufferAddition> Add<T> for AudioBuffer {
@@ -46,11 +138,37 @@ impl<T:AudioBufferAddition> AddAssign<T> for AudioBuffer {
 		self.combine_with(addition);
 	}
 }
+impl<T:AudioBufferAddition> Sub<T> for AudioBuffer {
+	type Output = AudioBuffer;
+
+	fn sub(self, addition:T) -> Self::Output {
+		self.subtracted(addition)
+	}
+}
+impl<T:AudioBufferAddition> SubAssign<T> for AudioBuffer {
+	fn sub_assign(&mut self, addition:T) {
+		self.subtract(addition);
+	}
+}
 
 
 
 pub trait AudioBufferAddition {
 	fn as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<Vec<f32>>;
+
+	fn try_as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<Vec<f32>>, AudioBufferError> where Self:Sized {
+		Ok(self.as_raw_list(target_channel_count, target_sample_rate))
+	}
+
+	/// Convert the addition to a list of raw additions matching the target settings, keeping their placement separate from the data.
+	fn into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<RawAddition> where Self:Sized {
+		self.as_raw_list(target_channel_count, target_sample_rate).into_iter().map(RawAddition::new).collect()
+	}
+
+	/// Convert the addition to a list of placed raw additions. Returns an error instead of panicking if the addition can not be resampled.
+	fn try_into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<RawAddition>, AudioBufferError> where Self:Sized {
+		Ok(self.try_as_raw_list(target_channel_count, target_sample_rate)?.into_iter().map(RawAddition::new).collect())
+	}
 }
 impl AudioBufferAddition for Vec<f32> {
 	fn as_raw_list(self, _target_channel_count:usize, _target_sample_rate:u32) -> Vec<Vec<f32>> {
@@ -66,9 +184,172 @@ impl AudioBufferAddition for AudioBuffer {
 	fn as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<Vec<f32>> {
 		vec![self.resampled(target_channel_count, target_sample_rate).data]
 	}
+
+	fn try_as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<Vec<f32>>, AudioBufferError> {
+		Ok(vec![self.try_resampled(target_channel_count, target_sample_rate)?.data])
+	}
+}
+impl AudioBufferAddition for Vec<RawAddition> {
+	fn as_raw_list(self, target_channel_count:usize, _target_sample_rate:u32) -> Vec<Vec<f32>> {
+		self.into_iter().map(|addition| addition.into_unplaced(target_channel_count)).collect()
+	}
+
+	fn into_placed_list(self, _target_channel_count:usize, _target_sample_rate:u32) -> Vec<RawAddition> {
+		self
+	}
 }
 impl AudioBufferAddition for Vec<AudioBuffer> {
 	fn as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<Vec<f32>> {
 		self.into_iter().map(|buffer| buffer.resampled(target_channel_count, target_sample_rate).data).collect()
 	}
-}
\ No newline at end of file
+
+	fn try_as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<Vec<f32>>, AudioBufferError> {
+		self.into_iter().map(|buffer| buffer.try_resampled(target_channel_count, target_sample_rate).map(|buffer| buffer.data)).collect()
+	}
+}
+impl<T:AudioBufferAddition> AudioBufferAddition for PlacedAddition<T> {
+	fn as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<Vec<f32>> {
+		self.into_placed_list(target_channel_count, target_sample_rate).into_iter().map(|addition| addition.into_unplaced(target_channel_count)).collect()
+	}
+
+	fn try_as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<Vec<f32>>, AudioBufferError> {
+		Ok(self.try_into_placed_list(target_channel_count, target_sample_rate)?.into_iter().map(|addition| addition.into_unplaced(target_channel_count)).collect())
+	}
+
+	fn into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<RawAddition> {
+		let frame_offset:isize = self.offset.frames(target_sample_rate);
+		self.addition.into_placed_list(target_channel_count, target_sample_rate).into_iter().map(|addition| addition.placed(frame_offset, self.gain)).collect()
+	}
+
+	fn try_into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<RawAddition>, AudioBufferError> {
+		let frame_offset:isize = self.offset.frames(target_sample_rate);
+		Ok(self.addition.try_into_placed_list(target_channel_count, target_sample_rate)?.into_iter().map(|addition| addition.placed(frame_offset, self.gain)).collect())
+	}
+}
+impl<T:AudioBufferAddition> AudioBufferAddition for Vec<PlacedAddition<T>> {
+	fn as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<Vec<f32>> {
+		self.into_iter().flat_map(|addition| addition.as_raw_list(target_channel_count, target_sample_rate)).collect()
+	}
+
+	fn try_as_raw_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<Vec<f32>>, AudioBufferError> {
+		Ok(self.into_iter().map(|addition| addition.try_as_raw_list(target_channel_count, target_sample_rate)).collect::<Result<Vec<Vec<Vec<f32>>>, AudioBufferError>>()?.into_iter().flatten().collect())
+	}
+
+	fn into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Vec<RawAddition> {
+		self.into_iter().flat_map(|addition| addition.into_placed_list(target_channel_count, target_sample_rate)).collect()
+	}
+
+	fn try_into_placed_list(self, target_channel_count:usize, target_sample_rate:u32) -> Result<Vec<RawAddition>, AudioBufferError> {
+		Ok(self.into_iter().map(|addition| addition.try_into_placed_list(target_channel_count, target_sample_rate)).collect::<Result<Vec<Vec<RawAddition>>, AudioBufferError>>()?.into_iter().flatten().collect())
+	}
+}
+
+
+
+/// Interleaved data that already matches the settings of the target buffer, placed at a frame offset with a gain.
+#[derive(Clone)]
+pub struct RawAddition {
+	pub(crate) data:Vec<f32>,
+	pub(crate) frame_offset:isize,
+	pub(crate) gain:f32
+}
+impl RawAddition {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new raw addition that starts at the first frame without gain.
+	pub fn new(data:Vec<f32>) -> RawAddition {
+		RawAddition {
+			data,
+			frame_offset: 0,
+			gain: 1.0
+		}
+	}
+
+	/// Move the addition by the given amount of frames and multiply its gain.
+	pub fn placed(mut self, frame_offset:isize, gain:f32) -> RawAddition {
+		self.frame_offset += frame_offset;
+		self.gain *= gain;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the interleaved data of the addition.
+	pub fn data(&self) -> &[f32] {
+		&self.data
+	}
+
+	/// Get the frame of the target buffer the addition starts at. A negative offset trims the start of the addition.
+	pub fn frame_offset(&self) -> isize {
+		self.frame_offset
+	}
+
+	/// Get the gain the addition is multiplied by.
+	pub fn gain(&self) -> f32 {
+		self.gain
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Apply the offset and gain to the data, padding or trimming the start.
+	fn into_unplaced(self, channel_count:usize) -> Vec<f32> {
+		let skipped_sample_count:usize = (self.frame_offset.min(0).unsigned_abs() * channel_count).min(self.data.len());
+		let padding:Vec<f32> = vec![0.0; self.frame_offset.max(0) as usize * channel_count];
+		padding.into_iter().chain(self.data[skipped_sample_count..].iter().map(|sample| sample * self.gain)).collect()
+	}
+}
+
+
+
+enum AdditionOffset {
+	Frames(isize),
+	Seconds(f64)
+}
+impl AdditionOffset {
+
+	/// Get the offset in frames at the given sample rate.
+	fn frames(&self, sample_rate:u32) -> isize {
+		match self {
+			AdditionOffset::Frames(frames) => *frames,
+			AdditionOffset::Seconds(seconds) => (seconds * sample_rate as f64).round() as isize
+		}
+	}
+}
+
+
+
+pub struct PlacedAddition<T:AudioBufferAddition> {
+	addition:T,
+	offset:AdditionOffset,
+	gain:f32
+}
+impl<T:AudioBufferAddition> PlacedAddition<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Place an addition at the given frame offset of the target buffer. A negative offset trims the start of the addition.
+	pub fn at_frame(addition:T, frame_offset:isize) -> PlacedAddition<T> {
+		PlacedAddition { addition, offset: AdditionOffset::Frames(frame_offset), gain: 1.0 }
+	}
+
+	/// Place an addition at the given offset in seconds. A negative offset trims the start of the addition.
+	pub fn at_seconds(addition:T, seconds:f64) -> PlacedAddition<T> {
+		PlacedAddition { addition, offset: AdditionOffset::Seconds(seconds), gain: 1.0 }
+	}
+
+	/// Place an addition at the given time.
+	pub fn at_time(addition:T, time:Duration) -> PlacedAddition<T> {
+		PlacedAddition::at_seconds(addition, time.as_secs_f64())
+	}
+
+	/// Return the placement with the given gain.
+	pub fn with_gain(mut self, gain:f32) -> Self {
+		self.gain = gain;
+		self
+	}
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
-	use crate::AudioBuffer;
+	use std::{ f32::consts::SQRT_2, time::Duration };
+	use crate::{ AudioBuffer, AudioBufferAddition, AudioBufferError, PlacedAddition, RawAddition };
 
 
 
@@ -32,7 +33,7 @@ mod test {
 		let addition:AudioBuffer = AudioBuffer::new((0..200).map(|index| [index as f32 * 0.001; 3]).flatten().collect(), 3, 2000);
 		let combined:AudioBuffer = original.combined_with(addition);
 
-		let expected:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32 * 0.003).collect(), 1, 1000);
+		let expected:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32 * 0.001 * (3.0 + 2.0 * SQRT_2)).collect(), 1, 1000);
 		
 		combined.assert_similar(&expected, 0.0001);
 	}
@@ -60,4 +61,99 @@ mod test {
 		
 		combined.assert_similar(&expected, 0.0001);
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn try_add_invalid_addition() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 1, 1000);
+		let addition:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 1, 0);
+		assert_eq!(original.try_combined_with(addition), Err(AudioBufferError::NoSampleRate));
+	}
+
+	#[test]
+	fn try_add_misaligned_raw_addition() {
+		let mut original:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 2, 1000);
+		assert_eq!(original.try_combine_with(vec![0.5; 101]), Err(AudioBufferError::MisalignedData { sample_count: 101, channel_count: 2 }));
+		assert_eq!(original.data, vec![0.0; 100]);
+	}
+
+	#[test]
+	fn add_polyphase_resampled_sample() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.0; 4800 * 2], 2, 48000);
+		let addition:AudioBuffer = AudioBuffer::new(vec![0.25; 4410 * 2], 2, 44100);
+		let combined:AudioBuffer = original.combined_with(addition);
+
+		let expected:AudioBuffer = AudioBuffer::new(vec![0.25; 4800 * 2], 2, 48000);
+
+		combined.assert_similar(&expected, 0.0001);
+	}
+
+	#[test]
+	fn add_at_frame_grows() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.1; 10], 2, 1000);
+		let combined:AudioBuffer = original.combined_at_frame(AudioBuffer::new(vec![0.2; 10], 2, 1000), 3, 0.5);
+
+		let expected:AudioBuffer = AudioBuffer::new([vec![0.1; 6], vec![0.2; 4], vec![0.1; 6]].concat(), 2, 1000);
+
+		combined.assert_similar(&expected, 0.0001);
+	}
+
+	#[test]
+	fn add_at_negative_frame_trims_start() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.0; 4], 1, 1000);
+		let combined:AudioBuffer = original.combined_at_frame(vec![0.1, 0.2, 0.3, 0.4], -2, 1.0);
+		assert_eq!(combined.data, vec![0.3, 0.4, 0.0, 0.0]);
+
+		let untouched:AudioBuffer = AudioBuffer::new(vec![0.0; 4], 1, 1000).combined_at_frame(vec![0.1, 0.2], -5, 1.0);
+		assert_eq!(untouched.data, vec![0.0; 4]);
+	}
+
+	#[test]
+	fn add_at_seconds_uses_target_rate() {
+		let original:AudioBuffer = AudioBuffer::new(Vec::new(), 1, 100);
+		let addition:AudioBuffer = AudioBuffer::new(vec![0.5; 10], 1, 100);
+		let combined:AudioBuffer = original.combined_at_seconds(addition, 2.5, 1.0);
+		assert_eq!(combined.frame_count(), 260);
+		assert!(combined.data[..250].iter().all(|sample| *sample == 0.0));
+		assert!(combined.data[250..].iter().all(|sample| (*sample - 0.5).abs() < 0.0001));
+	}
+
+	#[test]
+	fn add_multiple_placed_additions() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.0; 6], 1, 10);
+		let combined:AudioBuffer = original.combined_with(vec![
+			PlacedAddition::at_frame(vec![0.1, 0.1], 1),
+			PlacedAddition::at_time(vec![0.2, 0.2], Duration::from_millis(200)).with_gain(2.0)
+		]);
+		combined.assert_similar(&AudioBuffer::new(vec![0.0, 0.1, 0.5, 0.4, 0.0, 0.0], 1, 10), 0.0001);
+	}
+
+	#[test]
+	fn placed_addition_as_raw_list() {
+		let raw:Vec<Vec<f32>> = PlacedAddition::at_frame(vec![0.1, 0.2, 0.3, 0.4], 1).with_gain(2.0).as_raw_list(2, 10);
+		assert_eq!(raw, vec![vec![0.0, 0.0, 0.2, 0.4, 0.6, 0.8]]);
+		let trimmed:Vec<Vec<f32>> = PlacedAddition::at_frame(vec![0.1, 0.2, 0.3, 0.4], -1).as_raw_list(2, 10);
+		assert_eq!(trimmed, vec![vec![0.3, 0.4]]);
+	}
+
+	#[test]
+	fn placed_addition_into_placed_list() {
+		let placed:Vec<RawAddition> = PlacedAddition::at_frame(vec![0.1, 0.2], 3).with_gain(0.5).into_placed_list(2, 10);
+		assert_eq!((placed[0].data(), placed[0].frame_offset(), placed[0].gain()), (&[0.1, 0.2][..], 3, 0.5));
+		let combined:AudioBuffer = AudioBuffer::new(vec![0.0; 2], 2, 10).combined_with(vec![RawAddition::new(vec![0.4, 0.8]).placed(1, 0.5)]);
+		assert_eq!(combined.data(), &[0.0, 0.0, 0.2, 0.4]);
+	}
+
+	#[test]
+	fn try_add_placed_invalid_addition() {
+		let mut original:AudioBuffer = AudioBuffer::new(vec![0.0; 4], 1, 1000);
+		assert_eq!(original.try_combine_with(PlacedAddition::at_frame(AudioBuffer::new(vec![0.0; 4], 0, 1000), 2)), Err(AudioBufferError::NoChannels));
+	}
+
+	#[test]
+	fn add_empty_placed_addition_past_end() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.0; 4], 1, 1000);
+		let combined:AudioBuffer = original.combined_with(vec![PlacedAddition::at_frame(vec![0.5], 0), PlacedAddition::at_frame(Vec::<f32>::new(), 50)]);
+
+		assert_eq!(combined.data(), &[0.5, 0.0, 0.0, 0.0]);
+	}
+}
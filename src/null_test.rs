@@ -0,0 +1,83 @@
+use crate::{ AudioBuffer, analysis::{ decibels_to_gain, gain_to_decibels } };
+use std::{ ops::Range, time::Duration };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NullTestReport {
+	residual_peak:f32,
+	residual_rms_dbfs:f32,
+	ranges_above_threshold:Vec<Range<Duration>>
+}
+impl NullTestReport {
+
+	/// Get the highest absolute sample value of the residual.
+	pub fn residual_peak(&self) -> f32 {
+		self.residual_peak
+	}
+
+	/// Get the highest absolute sample value of the residual in decibels relative to full scale.
+	pub fn residual_peak_dbfs(&self) -> f32 {
+		gain_to_decibels(self.residual_peak)
+	}
+
+	/// Get the root mean square of the residual in decibels relative to full scale.
+	pub fn residual_rms_dbfs(&self) -> f32 {
+		self.residual_rms_dbfs
+	}
+
+	/// Get the time ranges in which the residual exceeds the threshold.
+	pub fn ranges_above_threshold(&self) -> &[Range<Duration>] {
+		&self.ranges_above_threshold
+	}
+
+	/// Whether the residual never exceeds the threshold.
+	pub fn is_null(&self) -> bool {
+		self.ranges_above_threshold.is_empty()
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Return the audio with inverted polarity.
+	pub fn inverted(mut self) -> Self {
+		self.invert();
+		self
+	}
+
+	/// Invert the polarity of the audio.
+	pub fn invert(&mut self) {
+		self.data.iter_mut().for_each(|sample| *sample = -*sample);
+	}
+
+	/// Subtract another buffer from this one and report on the residual. Resamples the other buffer if it doesn't match the sampling settings of self. Frames in which any channel of the residual exceeds the threshold in decibels relative to full scale are reported as time ranges.
+	pub fn null_test(&self, other:&AudioBuffer, threshold_dbfs:f32) -> NullTestReport {
+		let residual:AudioBuffer = self.clone().subtracted(other.clone());
+		let threshold:f32 = decibels_to_gain(threshold_dbfs);
+
+		let mut ranges_above_threshold:Vec<Range<Duration>> = Vec::new();
+		let mut range_start:Option<usize> = None;
+		for (frame_index, frame) in residual.frames().enumerate() {
+			let above_threshold:bool = frame.iter().any(|sample| sample.abs() > threshold);
+			match (above_threshold, range_start) {
+				(true, None) => range_start = Some(frame_index),
+				(false, Some(start)) => {
+					ranges_above_threshold.push(residual.frames_to_duration(start)..residual.frames_to_duration(frame_index));
+					range_start = None;
+				},
+				_ => {}
+			}
+		}
+		if let Some(start) = range_start {
+			ranges_above_threshold.push(residual.frames_to_duration(start)..residual.duration());
+		}
+
+		NullTestReport {
+			residual_peak: residual.peak(),
+			residual_rms_dbfs: residual.rms_dbfs(),
+			ranges_above_threshold
+		}
+	}
+}
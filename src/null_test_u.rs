@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, NullTestReport, PlacedAddition };
+
+
+
+	#[test]
+	fn invert_polarity() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.1, -0.2, 0.0], 1, 100);
+		assert_eq!(buffer.inverted().data, vec![-0.1, 0.2, -0.0]);
+	}
+
+	#[test]
+	fn subtract_buffers() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.5, 0.5, 0.5], 1, 100);
+		let difference:AudioBuffer = original.clone() - AudioBuffer::new(vec![0.2, 0.7], 1, 100);
+		difference.assert_similar(&AudioBuffer::new(vec![0.3, -0.2, 0.5], 1, 100), 0.0001);
+
+		let mut grown:AudioBuffer = original;
+		grown -= PlacedAddition::at_frame(vec![1.0, 1.0], 2);
+		grown.assert_similar(&AudioBuffer::new(vec![0.5, 0.5, -0.5, -1.0], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn subtract_does_not_clip() {
+		let difference:AudioBuffer = AudioBuffer::new(vec![-0.8], 1, 100) - vec![0.8];
+		assert_eq!(difference.data, vec![-1.6]);
+	}
+
+	#[test]
+	fn subtract_resamples() {
+		let difference:AudioBuffer = AudioBuffer::new(vec![0.5; 20], 2, 100) - AudioBuffer::new(vec![0.5; 5], 1, 50);
+		difference.assert_similar(&AudioBuffer::new(vec![0.0; 20], 2, 100), 0.0001);
+	}
+
+	#[test]
+	fn null_test_identical() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..100).map(|index| (index as f32 * 0.1).sin()).collect(), 1, 100);
+		let report:NullTestReport = buffer.null_test(&buffer, -90.0);
+		assert!(report.is_null());
+		assert_eq!(report.residual_peak(), 0.0);
+		assert_eq!(report.residual_rms_dbfs(), f32::NEG_INFINITY);
+	}
+
+	#[test]
+	fn null_test_reports_ranges() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.5; 100], 1, 100);
+		let mut changed:AudioBuffer = buffer.clone();
+		changed.data[10..20].iter_mut().for_each(|sample| *sample += 0.1);
+		changed.data[95..].iter_mut().for_each(|sample| *sample -= 0.001);
+
+		let report:NullTestReport = buffer.null_test(&changed, -40.0);
+		assert!((report.residual_peak() - 0.1).abs() < 0.0001);
+		assert!((report.residual_peak_dbfs() + 20.0).abs() < 0.01);
+		assert!((report.residual_rms_dbfs() + 30.0).abs() < 0.01);
+		assert_eq!(report.ranges_above_threshold(), &[Duration::from_millis(100)..Duration::from_millis(200)]);
+
+		let sensitive_report:NullTestReport = buffer.null_test(&changed, -70.0);
+		assert_eq!(sensitive_report.ranges_above_threshold().len(), 2);
+		assert_eq!(sensitive_report.ranges_above_threshold()[1], Duration::from_millis(950)..Duration::from_secs(1));
+	}
+}
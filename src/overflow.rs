@@ -0,0 +1,119 @@
+use std::{ collections::VecDeque, time::Duration };
+
+
+
+const DEFAULT_LIMITER_LOOKAHEAD:Duration = Duration::from_millis(5);
+const DEFAULT_LIMITER_RELEASE:Duration = Duration::from_millis(50);
+const LIMIT:f32 = 1.0;
+const SOFT_CLIP_KNEE:f32 = 0.5;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SoftClipCurve {
+	Tanh,
+	Cubic
+}
+impl SoftClipCurve {
+
+	/// Bend a sample above the knee towards the limit. The curve starts at the knee with a slope of 1.0, so samples below it pass through unchanged.
+	fn apply(&self, sample:f32) -> f32 {
+		let magnitude:f32 = sample.abs();
+		if magnitude <= SOFT_CLIP_KNEE {
+			return sample;
+		}
+		let headroom:f32 = LIMIT - SOFT_CLIP_KNEE;
+		let excess:f32 = magnitude - SOFT_CLIP_KNEE;
+		let bent:f32 = match self {
+			SoftClipCurve::Tanh => (excess / headroom).tanh(),
+			SoftClipCurve::Cubic => {
+				let progress:f32 = (excess / (1.5 * headroom)).min(1.0);
+				1.5 * progress - 0.5 * progress * progress * progress
+			}
+		};
+		(SOFT_CLIP_KNEE + headroom * bent).copysign(sample)
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum OverflowStrategy {
+	/// Keep samples outside of the range -1.0 to 1.0, using the headroom of floating point samples.
+	None,
+
+	/// Limit samples to the range -1.0 to 1.0.
+	#[default]
+	HardClip,
+
+	/// Bend samples above the soft clip knee smoothly towards the range -1.0 to 1.0, leaving quieter samples untouched.
+	SoftClip(SoftClipCurve),
+
+	/// Scale the whole buffer down if its peak exceeds 1.0.
+	Normalize,
+
+	/// Reduce the gain ahead of peaks exceeding 1.0 and smoothly recover it afterwards.
+	Limiter { lookahead:Duration, release:Duration }
+}
+impl OverflowStrategy {
+
+	/// Create a lookahead limiter with default timing.
+	pub fn limiter() -> OverflowStrategy {
+		OverflowStrategy::Limiter { lookahead: DEFAULT_LIMITER_LOOKAHEAD, release: DEFAULT_LIMITER_RELEASE }
+	}
+
+	/// Apply the strategy to interleaved data.
+	pub(crate) fn apply(&self, data:&mut [f32], channel_count:usize, sample_rate:u32) {
+		match self {
+			OverflowStrategy::None => {},
+			OverflowStrategy::HardClip => data.iter_mut().for_each(|sample| *sample = sample.clamp(-LIMIT, LIMIT)),
+			OverflowStrategy::SoftClip(curve) => data.iter_mut().for_each(|sample| *sample = curve.apply(*sample)),
+			OverflowStrategy::Normalize => {
+				let peak:f32 = data.iter().fold(0.0, |peak, sample| sample.abs().max(peak));
+				if peak > LIMIT {
+					data.iter_mut().for_each(|sample| *sample *= LIMIT / peak);
+				}
+			},
+			OverflowStrategy::Limiter { lookahead, release } => limit(data, channel_count, sample_rate, *lookahead, *release)
+		}
+	}
+}
+
+
+
+/// Apply a lookahead limiter to interleaved data. The required gain of each frame is held over the lookahead window and averaged over it, so the gain reaches its target exactly when the peak arrives. The gain then recovers with the release time.
+fn limit(data:&mut [f32], channel_count:usize, sample_rate:u32, lookahead:Duration, release:Duration) {
+	let frame_count:usize = data.len() / channel_count;
+	let lookahead_frames:usize = ((lookahead.as_secs_f64() * sample_rate as f64).round() as usize).max(1);
+	let release_frames:f64 = (release.as_secs_f64() * sample_rate as f64).max(1.0);
+	let release_coefficient:f32 = (1.0 - (-1.0 / release_frames).exp()) as f32;
+	let required_gains:Vec<f32> = data.chunks(channel_count).map(|frame| {
+		let peak:f32 = frame.iter().fold(0.0, |peak, sample| sample.abs().max(peak));
+		if peak > LIMIT { LIMIT / peak } else { 1.0 }
+	}).collect();
+
+	// Hold the lowest required gain of the upcoming lookahead window using a monotonic queue.
+	let mut held_gains:Vec<f32> = vec![1.0; frame_count];
+	let mut window:VecDeque<usize> = VecDeque::new();
+	for frame_index in (0..frame_count).rev() {
+		while window.back().map(|index| required_gains[*index] >= required_gains[frame_index]).unwrap_or(false) {
+			window.pop_back();
+		}
+		window.push_back(frame_index);
+		while window.front().map(|index| *index > frame_index + lookahead_frames).unwrap_or(false) {
+			window.pop_front();
+		}
+		held_gains[frame_index] = required_gains[window[0]];
+	}
+
+	// Smooth the held gain with a moving average and apply it with release.
+	let window_size:usize = lookahead_frames + 1;
+	let mut window_sum:f32 = window_size as f32;
+	let mut gain:f32 = 1.0;
+	for frame_index in 0..frame_count {
+		window_sum += held_gains[frame_index] - if frame_index >= window_size { held_gains[frame_index - window_size] } else { 1.0 };
+		let smoothed_gain:f32 = window_sum / window_size as f32;
+		gain = (gain + (1.0 - gain) * release_coefficient).min(smoothed_gain).min(required_gains[frame_index]);
+		data[frame_index * channel_count..(frame_index + 1) * channel_count].iter_mut().for_each(|sample| *sample *= gain);
+	}
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod test {
+	use std::{ f32::consts::SQRT_2, time::Duration };
+	use crate::{ AudioBuffer, OverflowStrategy, PlacedAddition, SoftClipCurve };
+
+
+
+	fn loud_ramp() -> AudioBuffer {
+		AudioBuffer::new((0..100).map(|index| (index as f32 - 50.0) * 0.04).collect(), 1, 1000)
+	}
+
+
+
+	#[test]
+	fn overflow_none_keeps_headroom() {
+		let combined:AudioBuffer = loud_ramp().combined_with_overflow(loud_ramp(), OverflowStrategy::None);
+		combined.assert_similar(&AudioBuffer::new((0..100).map(|index| (index as f32 - 50.0) * 0.08).collect(), 1, 1000), 0.0001);
+	}
+
+	#[test]
+	fn overflow_hard_clip() {
+		let combined:AudioBuffer = AudioBuffer::new(Vec::new(), 1, 1000).combined_with_overflow(loud_ramp(), OverflowStrategy::HardClip);
+		assert_eq!(combined.data, loud_ramp().data.iter().map(|sample| sample.clamp(-1.0, 1.0)).collect::<Vec<f32>>());
+		assert_eq!(combined.peak(), 1.0);
+	}
+
+	#[test]
+	fn overflow_soft_clip() {
+		for curve in [SoftClipCurve::Tanh, SoftClipCurve::Cubic] {
+			let clipped:AudioBuffer = AudioBuffer::new(Vec::new(), 1, 1000).combined_with_overflow(loud_ramp(), OverflowStrategy::SoftClip(curve));
+			assert!(clipped.peak() <= 1.0);
+			assert!(clipped.data.windows(2).all(|pair| pair[1] >= pair[0]));
+			assert_eq!(clipped.data[50], 0.0);
+		}
+		for curve in [SoftClipCurve::Tanh, SoftClipCurve::Cubic] {
+			let clipped:AudioBuffer = AudioBuffer::new(Vec::new(), 1, 1000).combined_with_overflow(vec![-0.5, -0.25, 0.0, 0.3, 0.5, 3.0], OverflowStrategy::SoftClip(curve));
+			assert_eq!(clipped.data[..5], [-0.5, -0.25, 0.0, 0.3, 0.5]);
+			assert!(clipped.data[5] > 0.5 && clipped.data[5] <= 1.0);
+		}
+	}
+
+	#[test]
+	fn overflow_only_affects_added_range() {
+		let mut buffer:AudioBuffer = AudioBuffer::new(vec![0.9; 10], 1, 1000);
+		buffer.combine_with_overflow(PlacedAddition::at_frame(vec![0.9; 2], 4), OverflowStrategy::SoftClip(SoftClipCurve::Tanh));
+		let once:Vec<f32> = buffer.data.clone();
+		buffer.combine_with_overflow(PlacedAddition::at_frame(vec![0.0; 2], 8), OverflowStrategy::SoftClip(SoftClipCurve::Tanh));
+		assert_eq!(buffer.data[..8], once[..8]);
+		assert_eq!(buffer.data[..4], [0.9; 4]);
+		assert!(buffer.data[4] < 1.0 && buffer.data[4] > 0.9);
+	}
+
+	#[test]
+	fn overflow_skips_gaps_between_additions() {
+		let mut buffer:AudioBuffer = AudioBuffer::new(vec![0.9; 10], 1, 1000);
+		buffer.combine_with_overflow(vec![PlacedAddition::at_frame(vec![0.0; 2], 0), PlacedAddition::at_frame(vec![0.0; 2], 8)], OverflowStrategy::SoftClip(SoftClipCurve::Tanh));
+		assert_eq!(buffer.data[2..8], [0.9; 6]);
+		assert!(buffer.data[0] < 0.9 && buffer.data[9] < 0.9);
+	}
+
+	#[test]
+	fn overflow_normalize_scales_whole_buffer() {
+		let normalized:AudioBuffer = AudioBuffer::new(vec![0.5; 10], 1, 1000).combined_with_overflow(PlacedAddition::at_frame(vec![2.0], 4), OverflowStrategy::Normalize);
+		normalized.assert_similar(&AudioBuffer::new([vec![0.2; 4], vec![1.0], vec![0.2; 5]].concat(), 1, 1000), 0.0001);
+	}
+
+	#[test]
+	fn overflow_normalize() {
+		let normalized:AudioBuffer = loud_ramp().combined_with_overflow(loud_ramp(), OverflowStrategy::Normalize);
+		normalized.assert_similar(&AudioBuffer::new((0..100).map(|index| (index as f32 - 50.0) / 50.0).collect(), 1, 1000), 0.0001);
+	}
+
+	#[test]
+	fn overflow_limiter() {
+		let mut data:Vec<f32> = vec![0.5; 2000];
+		data[1000] = 2.0;
+		let limited:AudioBuffer = AudioBuffer::new(Vec::new(), 1, 1000).combined_with_overflow(data, OverflowStrategy::Limiter { lookahead: Duration::from_millis(10), release: Duration::from_millis(50) });
+		assert!(limited.peak() <= 1.0);
+		assert!((limited.data[1000] - 1.0).abs() < 0.0001);
+		assert_eq!(limited.data[..990], [0.5; 990]);
+		assert!(limited.data[990..1000].windows(2).all(|pair| pair[1] <= pair[0]));
+		assert!(limited.data[1001..].windows(2).all(|pair| pair[1] >= pair[0]));
+		assert!((limited.data[1999] - 0.5).abs() < 0.001);
+	}
+
+	#[test]
+	fn overflow_limiter_keeps_quiet_audio() {
+		let quiet:AudioBuffer = AudioBuffer::new((0..100).map(|index| (index as f32 * 0.1).sin() * 0.9).collect(), 2, 1000);
+		assert_eq!(quiet.clone().combined_with_overflow(Vec::<f32>::new(), OverflowStrategy::limiter()), quiet);
+	}
+
+	#[test]
+	fn overflow_downmix() {
+		let stereo:AudioBuffer = AudioBuffer::new(vec![0.5; 20], 2, 1000);
+		stereo.resampled(1, 1000).assert_similar(&AudioBuffer::new(vec![0.5 * SQRT_2; 10], 1, 1000), 0.0001);
+
+		let loud_stereo:AudioBuffer = AudioBuffer::new(vec![0.9; 20], 2, 1000);
+		loud_stereo.clone().resampled(1, 1000).assert_similar(&AudioBuffer::new(vec![1.0; 10], 1, 1000), 0.0001);
+		let mono:AudioBuffer = loud_stereo.resampled_with_overflow(1, 1000, OverflowStrategy::None);
+		mono.assert_similar(&AudioBuffer::new(vec![0.9 * SQRT_2; 10], 1, 1000), 0.0001);
+	}
+}
@@ -0,0 +1,46 @@
+use crate::{ AudioBuffer, ResampleQuality, TimeStretchMode, interpolation::Interpolator, time_stretching::time_stretch_interleaved };
+
+
+
+const CENTS_PER_OCTAVE:f32 = 1200.0;
+const CENTS_PER_SEMITONE:f32 = 100.0;
+
+
+
+impl AudioBuffer {
+
+	/// Return the audio-buffer shifted in pitch by the given amount of semitones and cents, keeping the duration.
+	pub fn pitch_shifted(mut self, semitones:f32, cents:f32, mode:TimeStretchMode) -> Self {
+		self.pitch_shift(semitones, cents, mode);
+		self
+	}
+
+	/// Shift the pitch of the audio by the given amount of semitones and cents, keeping the duration. The channel count and sample rate are kept.
+	pub fn pitch_shift(&mut self, semitones:f32, cents:f32, mode:TimeStretchMode) {
+		self.data = pitch_shift_interleaved(&self.data, self.channel_count, self.sample_rate, pitch_ratio(semitones, cents), mode);
+	}
+}
+
+
+
+/// Get the frequency ratio for a pitch shift of the given amount of semitones and cents.
+pub(crate) fn pitch_ratio(semitones:f32, cents:f32) -> f64 {
+	2.0_f64.powf(((semitones * CENTS_PER_SEMITONE + cents) / CENTS_PER_OCTAVE) as f64)
+}
+
+/// Shift the pitch of interleaved data by the given frequency ratio, keeping the amount of frames. The audio is time-stretched by the ratio and then read back at the ratio using band-limited interpolation. All channels share the same stretch and read positions, so they stay phase-coherent.
+pub(crate) fn pitch_shift_interleaved(data:&[f32], channel_count:usize, sample_rate:u32, pitch_ratio:f64, mode:TimeStretchMode) -> Vec<f32> {
+	let frame_count:usize = data.len() / channel_count;
+	if frame_count == 0 || pitch_ratio == 1.0 || !pitch_ratio.is_finite() || pitch_ratio <= 0.0 {
+		return data.to_vec();
+	}
+	let stretched_frame_count:usize = ((frame_count as f64 * pitch_ratio).round() as usize).max(1);
+	let stretched:Vec<f32> = time_stretch_interleaved(data, channel_count, sample_rate, stretched_frame_count, mode);
+	let interpolator:Interpolator = Interpolator::new(ResampleQuality::default(), 1.0 / pitch_ratio);
+	let mut output:Vec<f32> = vec![0.0; data.len()];
+	let mut weights:Vec<(isize, f64)> = Vec::new();
+	for (frame_index, frame) in output.chunks_mut(channel_count).enumerate() {
+		interpolator.interpolate_frame(&stretched, channel_count, frame_index as f64 * pitch_ratio, frame, &mut weights);
+	}
+	output
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, ChannelLayout, TimeStretchMode, test_helpers::{ estimated_frequency, mono_sine_wave } };
+
+
+
+	const MODES:[TimeStretchMode; 2] = [TimeStretchMode::Wsola, TimeStretchMode::PhaseVocoder];
+
+
+
+	#[test]
+	fn pitch_shift_octave() {
+		for mode in MODES {
+			let up:AudioBuffer = mono_sine_wave(440.0, 8000, 8000).pitch_shifted(12.0, 0.0, mode);
+			assert_eq!(up.frame_count(), 8000);
+			assert!((estimated_frequency(&up) - 880.0).abs() < 880.0 * 0.02);
+
+			let down:AudioBuffer = mono_sine_wave(440.0, 8000, 8000).pitch_shifted(-12.0, 0.0, mode);
+			assert_eq!(down.frame_count(), 8000);
+			assert!((estimated_frequency(&down) - 220.0).abs() < 220.0 * 0.02);
+		}
+	}
+
+	#[test]
+	fn pitch_shift_cents() {
+		let shifted:AudioBuffer = mono_sine_wave(400.0, 16000, 8000).pitch_shifted(4.0, 50.0, TimeStretchMode::PhaseVocoder);
+		let expected_frequency:f32 = 400.0 * 2.0_f32.powf(450.0 / 1200.0);
+		assert!((estimated_frequency(&shifted) - expected_frequency).abs() < expected_frequency * 0.01);
+	}
+
+	#[test]
+	fn pitch_shift_keeps_settings() {
+		let wave:AudioBuffer = AudioBuffer::new(vec![0.0; 2000], 2, 8000).with_channel_layout(ChannelLayout::Stereo);
+		let shifted:AudioBuffer = wave.pitch_shifted(3.0, 0.0, TimeStretchMode::Wsola);
+		assert_eq!(shifted.frame_count(), 1000);
+		assert_eq!(shifted.sample_rate, 8000);
+		assert_eq!(shifted.channel_layout(), Some(&ChannelLayout::Stereo));
+	}
+
+	#[test]
+	fn pitch_shift_keeps_channels_coherent() {
+		for mode in MODES {
+			let wave:AudioBuffer = mono_sine_wave(330.0, 4000, 8000);
+			let stereo:AudioBuffer = AudioBuffer::new(wave.data.iter().flat_map(|sample| [*sample, -*sample]).collect(), 2, 8000);
+			let shifted:AudioBuffer = stereo.pitch_shifted(5.0, 0.0, mode);
+			for frame in shifted.frames() {
+				assert!((frame[0] + frame[1]).abs() < 0.0001);
+			}
+		}
+	}
+
+	#[test]
+	fn pitch_shift_zero_is_unchanged() {
+		let wave:AudioBuffer = mono_sine_wave(440.0, 1000, 8000);
+		assert_eq!(wave.clone().pitch_shifted(0.0, 0.0, TimeStretchMode::Wsola), wave);
+	}
+}
@@ -0,0 +1,84 @@
+use crate::interpolation::{ SincKernel, reflected_sample, resampled_frame_count };
+
+
+
+const MAX_PHASE_COUNT:u64 = 4096;
+const MAX_BANK_SIZE:u64 = 1 << 22; // Filter weights over all phases.
+
+
+
+pub(crate) struct PolyphaseFilter {
+	pub(crate) interpolation_factor:u64,
+	pub(crate) decimation_factor:u64,
+	first_tap_offset:isize,
+	bank:Vec<Vec<f64>>
+}
+impl PolyphaseFilter {
+
+	/// Create a polyphase filter that converts between the given sample rates. The rate ratio is reduced to interpolation factor L over decimation factor M, and a windowed sinc filter is precomputed for each of the L phases. Returns None if the reduced ratio needs too many phases or filter weights.
+	pub fn new(source_sample_rate:u32, target_sample_rate:u32, taps:usize, kaiser_beta:f32) -> Option<PolyphaseFilter> {
+		let divisor:u64 = greatest_common_divisor(source_sample_rate as u64, target_sample_rate as u64);
+		let interpolation_factor:u64 = target_sample_rate as u64 / divisor;
+		let decimation_factor:u64 = source_sample_rate as u64 / divisor;
+		if divisor == 0 || interpolation_factor > MAX_PHASE_COUNT {
+			return None;
+		}
+
+		// Build a filter for each phase, the offset between the output position and the input frame before it.
+		let cutoff:f64 = (interpolation_factor as f64 / decimation_factor as f64).min(1.0);
+		let kernel:SincKernel = SincKernel::new(taps, kaiser_beta as f64, cutoff);
+		let reach:isize = kernel.reach() as isize;
+		if interpolation_factor.saturating_mul(2 * reach as u64 + 2) > MAX_BANK_SIZE {
+			return None;
+		}
+		let bank:Vec<Vec<f64>> = (0..interpolation_factor).map(|phase|
+			if phase == 0 && cutoff >= 1.0 {
+				(-reach..=reach + 1).map(|tap_offset| if tap_offset == 0 { 1.0 } else { 0.0 }).collect()
+			} else {
+				let phase_offset:f64 = phase as f64 / interpolation_factor as f64;
+				(-reach..=reach + 1).map(|tap_offset| kernel.weight(phase_offset - tap_offset as f64)).collect()
+			}
+		).collect();
+
+		Some(PolyphaseFilter {
+			interpolation_factor,
+			decimation_factor,
+			first_tap_offset: -reach,
+			bank
+		})
+	}
+
+	/// Resample interleaved data. The output has the exact amount of frames for the rate ratio, rounded to the nearest frame.
+	pub fn resample_interleaved(&self, data:&[f32], channel_count:usize) -> Vec<f32> {
+		let source_frame_count:usize = data.len() / channel_count;
+		let target_frame_count:usize = resampled_frame_count(source_frame_count, self.decimation_factor as u32, self.interpolation_factor as u32);
+		let last_frame_index:isize = source_frame_count as isize - 1;
+		let mut output:Vec<f32> = vec![0.0; target_frame_count * channel_count];
+		for (target_frame_index, target_frame) in output.chunks_mut(channel_count).enumerate() {
+			let scaled_position:u64 = target_frame_index as u64 * self.decimation_factor;
+			let base_frame_index:isize = (scaled_position / self.interpolation_factor) as isize;
+			let filter:&[f64] = &self.bank[(scaled_position % self.interpolation_factor) as usize];
+			let first_frame_index:isize = base_frame_index + self.first_tap_offset;
+			let fully_inside:bool = first_frame_index >= 0 && first_frame_index + filter.len() as isize - 1 <= last_frame_index;
+
+			for (channel_index, target_sample) in target_frame.iter_mut().enumerate() {
+				*target_sample = if fully_inside {
+					filter.iter().enumerate().map(|(tap_index, weight)| data[(first_frame_index as usize + tap_index) * channel_count + channel_index] as f64 * weight).sum::<f64>() as f32
+				} else {
+					filter.iter().enumerate().map(|(tap_index, weight)| reflected_sample(data, channel_count, first_frame_index + tap_index as isize, channel_index) * weight).sum::<f64>() as f32
+				};
+			}
+		}
+		output
+	}
+}
+
+
+
+/// Get the greatest common divisor of two numbers.
+pub(crate) fn greatest_common_divisor(mut a:u64, mut b:u64) -> u64 {
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	a
+}
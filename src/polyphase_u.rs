@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, ResampleQuality, interpolation::Interpolator, polyphase::{ PolyphaseFilter, greatest_common_divisor }, test_helpers::sine_wave };
+
+
+
+	#[test]
+	fn greatest_common_divisor_accurate() {
+		assert_eq!(greatest_common_divisor(44100, 48000), 300);
+		assert_eq!(greatest_common_divisor(48000, 96000), 48000);
+		assert_eq!(greatest_common_divisor(7, 13), 1);
+	}
+
+	#[test]
+	fn reduces_common_ratios() {
+		let ratio = |source_sample_rate:u32, target_sample_rate:u32| PolyphaseFilter::new(source_sample_rate, target_sample_rate, 32, 8.6).map(|filter| (filter.interpolation_factor, filter.decimation_factor));
+		assert_eq!(ratio(44100, 48000), Some((160, 147)));
+		assert_eq!(ratio(48000, 44100), Some((147, 160)));
+		assert_eq!(ratio(48000, 96000), Some((2, 1)));
+		assert_eq!(ratio(88200, 48000), Some((80, 147)));
+		assert_eq!(ratio(44100, 44099), None);
+		assert_eq!(ratio(48000, 1), Some((1, 48000)));
+		assert_eq!(ratio(4_000_000, 1), None);
+	}
+
+	#[test]
+	fn extreme_downsampling_stays_bounded() {
+		let original:AudioBuffer = AudioBuffer::new(vec![0.5; 96000], 1, 48000);
+		let resampled:AudioBuffer = original.resampled(1, 1);
+		assert_eq!(resampled.frame_count(), 2);
+		resampled.assert_similar(&AudioBuffer::new(vec![0.5; 2], 1, 1), 0.01);
+	}
+
+	#[test]
+	fn matches_direct_sinc_interpolation() {
+		let original:AudioBuffer = sine_wave(1000.0, 2000, 2, 44100);
+		let filter:PolyphaseFilter = PolyphaseFilter::new(44100, 48000, 32, 8.6).unwrap();
+		let polyphase_data:Vec<f32> = filter.resample_interleaved(&original.data, 2);
+
+		let interpolator:Interpolator = Interpolator::new(ResampleQuality::WindowedSinc { taps: 32, kaiser_beta: 8.6 }, 48000.0 / 44100.0);
+		let mut direct_data:Vec<f32> = vec![0.0; polyphase_data.len()];
+		let mut weights:Vec<(isize, f64)> = Vec::new();
+		for (frame_index, frame) in direct_data.chunks_mut(2).enumerate() {
+			interpolator.interpolate_frame(&original.data, 2, frame_index as f64 * 44100.0 / 48000.0, frame, &mut weights);
+		}
+
+		AudioBuffer::new(polyphase_data, 2, 48000).assert_similar(&AudioBuffer::new(direct_data, 2, 48000), 0.00001);
+	}
+
+	#[test]
+	fn resample_uses_polyphase() {
+		let original:AudioBuffer = sine_wave(440.0, 44100, 1, 44100);
+		let resampled:AudioBuffer = original.clone().resampled(1, 48000);
+		assert_eq!(resampled.frame_count(), 48000);
+		assert_eq!(resampled.data, PolyphaseFilter::new(44100, 48000, 32, 8.6).unwrap().resample_interleaved(&original.data, 1));
+		resampled.assert_similar(&sine_wave(440.0, 48000, 1, 48000), 0.001);
+	}
+}
@@ -0,0 +1,148 @@
+use crate::{ AudioBuffer, AudioBufferError, ResampleQuality, interpolation::{ Interpolator, resampled_frame_count } };
+
+
+
+pub struct Resampler {
+	channel_count:usize,
+	source_sample_rate:u32,
+	target_sample_rate:u32,
+	interpolator:Interpolator,
+	weights:Vec<(isize, f64)>,
+	history:Vec<f32>,
+	history_start_frame:u64,
+	emitted_frame_count:u64
+}
+impl Resampler {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new streaming resampler. Keeps its filter history and phase between blocks, so consecutive blocks are resampled without clicks at the boundaries. Processing panics if the channel count is zero, and a sample rate of zero leaves the filter without a valid width, use `try_new` to check the settings up front.
+	pub fn new(channel_count:usize, source_sample_rate:u32, target_sample_rate:u32, quality:ResampleQuality) -> Resampler {
+		Resampler {
+			channel_count,
+			source_sample_rate,
+			target_sample_rate,
+			interpolator: Interpolator::new(quality, target_sample_rate as f64 / source_sample_rate as f64),
+			weights: Vec::new(),
+			history: Vec::new(),
+			history_start_frame: 0,
+			emitted_frame_count: 0
+		}
+	}
+
+	/// Try to create a new streaming resampler. Returns an error if the channel count or either sample rate is zero.
+	pub fn try_new(channel_count:usize, source_sample_rate:u32, target_sample_rate:u32, quality:ResampleQuality) -> Result<Resampler, AudioBufferError> {
+		AudioBuffer::validate_settings(channel_count, source_sample_rate)?;
+		AudioBuffer::validate_settings(channel_count, target_sample_rate)?;
+		Ok(Resampler::new(channel_count, source_sample_rate, target_sample_rate, quality))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the channel count of the resampled data.
+	pub fn channel_count(&self) -> usize {
+		self.channel_count
+	}
+
+	/// Get the sample rate of the input data.
+	pub fn source_sample_rate(&self) -> u32 {
+		self.source_sample_rate
+	}
+
+	/// Get the sample rate of the output data.
+	pub fn target_sample_rate(&self) -> u32 {
+		self.target_sample_rate
+	}
+
+	/// Get the quality used for resampling.
+	pub fn quality(&self) -> ResampleQuality {
+		self.interpolator.quality()
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Resample a block of interleaved data. Returns all output frames that can be calculated with the data received so far. Incomplete frames are kept until the next block completes them.
+	pub fn process(&mut self, input:&[f32]) -> Vec<f32> {
+		self.history.extend_from_slice(input);
+		let received_frame_count:u64 = self.history_start_frame + (self.history.len() / self.channel_count) as u64;
+		let reach:u64 = self.interpolator.reach() as u64;
+
+		// Emit all frames of which the entire filter reach has been received.
+		let mut output:Vec<f32> = Vec::new();
+		while self.source_position(self.emitted_frame_count).0 + reach < received_frame_count {
+			self.emit_frame(None, &mut output);
+		}
+
+		// Remove history that is no longer in reach of the next frame. The first frames are kept while they are needed to extrapolate the start.
+		let first_needed_frame:u64 = self.source_position(self.emitted_frame_count).0.saturating_sub(reach);
+		if first_needed_frame > self.history_start_frame {
+			self.history.drain(..(first_needed_frame - self.history_start_frame) as usize * self.channel_count);
+			self.history_start_frame = first_needed_frame;
+		}
+		output
+	}
+
+	/// Resample a block of audio. The block should have the channel count and source sample rate of the resampler.
+	pub fn process_buffer(&mut self, block:&AudioBuffer) -> AudioBuffer {
+		AudioBuffer::new(self.process(&block.data), self.channel_count, self.target_sample_rate)
+	}
+
+	/// Drain the filter tail, returning the last output frames. After flushing, the total output has the exact amount of frames for the total input and rate ratio. The resampler is reset afterwards.
+	pub fn flush(&mut self) -> Vec<f32> {
+		let received_frame_count:u64 = self.history_start_frame + (self.history.len() / self.channel_count) as u64;
+		let target_frame_count:u64 = resampled_frame_count(received_frame_count as usize, self.source_sample_rate, self.target_sample_rate) as u64;
+		let mut output:Vec<f32> = Vec::new();
+		while self.emitted_frame_count < target_frame_count {
+			self.emit_frame(Some(received_frame_count as i64 - 1), &mut output);
+		}
+		self.reset();
+		output
+	}
+
+	/// Clear all history and start over as if the resampler was just created.
+	pub fn reset(&mut self) {
+		self.history.clear();
+		self.history_start_frame = 0;
+		self.emitted_frame_count = 0;
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Get the input frame before an output frame and the fraction towards the next input frame. Calculated from the absolute frame index to prevent drift.
+	fn source_position(&self, target_frame_index:u64) -> (u64, f64) {
+		let scaled_position:u128 = target_frame_index as u128 * self.source_sample_rate as u128;
+		let target_sample_rate:u128 = self.target_sample_rate as u128;
+		((scaled_position / target_sample_rate) as u64, (scaled_position % target_sample_rate) as f64 / target_sample_rate as f64)
+	}
+
+	/// Calculate the next output frame and add it to the output. Frames before the start are extrapolated from the first frames. When the last frame index is known, frames after it are extrapolated from the last frames.
+	fn emit_frame(&mut self, last_frame_index:Option<i64>, output:&mut Vec<f32>) {
+		let (base_frame_index, fraction) = self.source_position(self.emitted_frame_count);
+		self.interpolator.weights(fraction, &mut self.weights);
+		let weights:&mut Vec<(isize, f64)> = &mut self.weights;
+		weights.iter_mut().for_each(|(frame_index, _)| *frame_index += base_frame_index as isize);
+		if self.interpolator.quality() == ResampleQuality::Nearest {
+			weights.iter_mut().for_each(|(frame_index, _)| *frame_index = (*frame_index).clamp(0, last_frame_index.unwrap_or(i64::MAX) as isize));
+		}
+
+		for channel_index in 0..self.channel_count {
+			let sample_at = |frame_index:i64| self.history[(frame_index - self.history_start_frame as i64) as usize * self.channel_count + channel_index] as f64;
+			output.push(weights.iter().map(|(frame_index, weight)| {
+				let frame_index:i64 = *frame_index as i64;
+				let value:f64 = match last_frame_index {
+					_ if frame_index < 0 => 2.0 * sample_at(0) - sample_at((-frame_index).min(last_frame_index.unwrap_or(i64::MAX))),
+					Some(last_frame_index) if frame_index > last_frame_index => 2.0 * sample_at(last_frame_index) - sample_at((2 * last_frame_index - frame_index).max(0)),
+					_ => sample_at(frame_index)
+				};
+				value * weight
+			}).sum::<f64>() as f32);
+		}
+		self.emitted_frame_count += 1;
+	}
+}
\ No newline at end of file
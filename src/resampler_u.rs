@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferError, ResampleQuality, Resampler, test_helpers::sine_wave };
+
+
+
+	fn resample_in_blocks(resampler:&mut Resampler, data:&[f32], block_sizes:&[usize]) -> Vec<f32> {
+		let mut output:Vec<f32> = Vec::new();
+		let mut cursor:usize = 0;
+		for block_size in block_sizes.iter().cycle() {
+			if cursor >= data.len() {
+				break;
+			}
+			let block_end:usize = (cursor + block_size).min(data.len());
+			output.extend(resampler.process(&data[cursor..block_end]));
+			cursor = block_end;
+		}
+		output.extend(resampler.flush());
+		output
+	}
+
+
+
+	#[test]
+	fn blocks_match_whole_buffer() {
+		let original:AudioBuffer = sine_wave(440.0, 4000, 2, 44100);
+		for quality in [ResampleQuality::Nearest, ResampleQuality::Linear, ResampleQuality::Cubic, ResampleQuality::default()] {
+			let mut resampler:Resampler = Resampler::new(2, 44100, 48000, quality);
+			let streamed:AudioBuffer = AudioBuffer::new(resample_in_blocks(&mut resampler, &original.data, &[2, 15, 64, 1, 999, 256]), 2, 48000);
+			streamed.assert_similar(&original.clone().resampled_with_quality(2, 48000, quality), 0.00001);
+		}
+	}
+
+	#[test]
+	fn downsampling_blocks_match_whole_buffer() {
+		let original:AudioBuffer = sine_wave(1000.0, 9600, 1, 96000);
+		let mut resampler:Resampler = Resampler::new(1, 96000, 44100, ResampleQuality::default());
+		let streamed:AudioBuffer = AudioBuffer::new(resample_in_blocks(&mut resampler, &original.data, &[480]), 1, 44100);
+		streamed.assert_similar(&original.resampled(1, 44100), 0.00001);
+	}
+
+	#[test]
+	fn output_size_follows_input() {
+		let mut resampler:Resampler = Resampler::new(1, 1000, 2000, ResampleQuality::Linear);
+		assert_eq!(resampler.process(&[0.0; 100]).len(), 198);
+		assert_eq!(resampler.process(&[0.0; 100]).len(), 200);
+		assert_eq!(resampler.flush().len(), 2);
+	}
+
+	#[test]
+	fn keeps_incomplete_frames() {
+		let mut resampler:Resampler = Resampler::new(2, 1000, 1000, ResampleQuality::Linear);
+		assert_eq!(resampler.process(&[0.1, 0.2, 0.3]), Vec::<f32>::new());
+		assert_eq!(resampler.process(&[0.4, 0.5, 0.6]), vec![0.1, 0.2, 0.3, 0.4]);
+		assert_eq!(resampler.flush(), vec![0.5, 0.6]);
+	}
+
+	#[test]
+	fn flush_resets() {
+		let original:AudioBuffer = sine_wave(440.0, 1000, 1, 44100);
+		let mut resampler:Resampler = Resampler::new(1, 44100, 48000, ResampleQuality::default());
+		let first_run:Vec<f32> = resample_in_blocks(&mut resampler, &original.data, &[100]);
+		let second_run:Vec<f32> = resample_in_blocks(&mut resampler, &original.data, &[100]);
+		assert_eq!(first_run, second_run);
+	}
+
+	#[test]
+	fn try_new_validates_settings() {
+		assert!(Resampler::try_new(2, 44100, 48000, ResampleQuality::default()).is_ok());
+		assert!(matches!(Resampler::try_new(0, 44100, 48000, ResampleQuality::default()), Err(AudioBufferError::NoChannels)));
+		assert!(matches!(Resampler::try_new(2, 0, 48000, ResampleQuality::default()), Err(AudioBufferError::NoSampleRate)));
+		assert!(matches!(Resampler::try_new(2, 44100, 0, ResampleQuality::default()), Err(AudioBufferError::NoSampleRate)));
+	}
+}
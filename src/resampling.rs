@@ -1,4 +1,4 @@
-use crate::AudioBuffer;
+use crate::{ AudioBuffer, AudioBufferError, ChannelLayout, ChannelMatrix, OverflowStrategy, ResampleQuality, interpolation::{ resample_interleaved, resampled_frame_count } };
 
 
 
@@ -10,100 +10,100 @@ impl AudioBuffer {
 		self
 	}
 
-	/// Resample the audio. Automatically picks the best available algorithms depending on the buffer.
+	/// Return the audio-buffer resampled. Returns an error instead of panicking if the buffer or the target settings are invalid.
+	pub fn try_resampled(mut self, channel_count:usize, sample_rate:u32) -> Result<Self, AudioBufferError> {
+		self.try_resample(channel_count, sample_rate)?;
+		Ok(self)
+	}
+
+	/// Resample the audio. Returns an error instead of panicking if the buffer or the target settings are invalid.
+	pub fn try_resample(&mut self, channel_count:usize, sample_rate:u32) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		Self::validate_settings(channel_count, sample_rate)?;
+		self.resample(channel_count, sample_rate);
+		Ok(())
+	}
+
+	/// Resample the audio. Automatically picks the best available algorithms depending on the buffer. When the channel layout is known, channels are converted using the standard matrix to the most common layout for the new channel count.
 	pub fn resample(&mut self, channel_count:usize, sample_rate:u32) {
-		if channel_count < self.channel_count {
-			self.shrink_channels(channel_count);
-		}
-		if channel_count > self.channel_count {
-			self.grow_channels(channel_count);
-		}
-		if sample_rate < self.sample_rate {
-			self.shrink_sample_rate_fast(sample_rate);
-		}
-		if sample_rate > self.sample_rate {
-			self.grow_sample_rate_fast(sample_rate);
-		}
+		self.resample_with_quality(channel_count, sample_rate, ResampleQuality::default());
 	}
-	
-	/// Shrink the channel count of this sample.
-	fn shrink_channels(&mut self, new_channel_count:usize) {
-
-		// Combine stereo to mono.
-		if self.channel_count == 2 && new_channel_count == 1 {
-			let data_len:usize = self.data.len();
-			let mut store_cursor:usize = 0;
-			let mut take_cursor:usize = 0;
-			while take_cursor < data_len {
-				self.data[store_cursor] = (self.data[take_cursor] + self.data[take_cursor + 1]).max(-1.0).min(1.0);
-				store_cursor += 1;
-				take_cursor += 2;
-			}
-			self.data.drain(..data_len / 2);
-		}
 
-		// Remove spare channels.
-		else {
-			let current_channel_count:usize = self.channel_count;
-			let mut index:usize = 0;
-			self.data.retain(|_| {
-				let keep:bool = index % current_channel_count > new_channel_count;
-				index += 1;
-				keep
-			});
-		}
+	/// Return the audio-buffer resampled using the given sample rate conversion quality.
+	pub fn resampled_with_quality(mut self, channel_count:usize, sample_rate:u32, quality:ResampleQuality) -> Self {
+		self.resample_with_quality(channel_count, sample_rate, quality);
+		self
+	}
 
-		// Set new channel count.
-		self.channel_count = new_channel_count;
+	/// Resample the audio using the given sample rate conversion quality. When the channel layout is known, channels are converted using the standard matrix to the most common layout for the new channel count. Otherwise they are converted by count and the layout is forgotten.
+	pub fn resample_with_quality(&mut self, channel_count:usize, sample_rate:u32, quality:ResampleQuality) {
+		self.resample_with_settings(channel_count, sample_rate, quality, OverflowStrategy::default());
 	}
 
-	/// Grow the channel count of this sample.
-	fn grow_channels(&mut self, new_channel_count:usize) {
-		let current_channel_count:usize = self.channel_count;
-		self.data = (0..self.data.len() / current_channel_count).map(|sample_index|
-			(0..new_channel_count).map(|channel_index|
-				self.data[sample_index * current_channel_count + (channel_index % current_channel_count)]
-			).collect::<Vec<f32>>()
-		).flatten().collect();
-		self.channel_count = new_channel_count;
+	/// Return the audio-buffer resampled, handling downmixed samples outside of the range -1.0 to 1.0 using the given strategy.
+	pub fn resampled_with_overflow(mut self, channel_count:usize, sample_rate:u32, overflow:OverflowStrategy) -> Self {
+		self.resample_with_overflow(channel_count, sample_rate, overflow);
+		self
 	}
 
-	/// Shrink the sample rate of this sample quickly, but slightly inaccurately.
-	fn shrink_sample_rate_fast(&mut self, new_sample_rate:u32) {
-		let current_sample_count:usize = self.data.len();
-		let channel_count:usize = self.channel_count;
-
-		let new_sample_count:usize = (current_sample_count as f32 / self.sample_rate as f32 * new_sample_rate as f32) as usize;
-		let samples_to_remove:usize = current_sample_count - new_sample_count;
-		let remove_sample_every:usize = current_sample_count / samples_to_remove; // Make sure to remove sample in each channel.
-		let max_mod_index:usize = remove_sample_every - channel_count;
-		
-		let mut index:usize = 0;
-		self.data.retain(|_| {
-			let keep:bool = index % remove_sample_every < max_mod_index;
-			index += 1;
-			keep
-		});
+	/// Resample the audio, handling downmixed samples outside of the range -1.0 to 1.0 using the given strategy.
+	pub fn resample_with_overflow(&mut self, channel_count:usize, sample_rate:u32, overflow:OverflowStrategy) {
+		self.resample_with_settings(channel_count, sample_rate, ResampleQuality::default(), overflow);
+	}
 
-		self.sample_rate = new_sample_rate;
+	/// Resample the audio using the given quality and overflow strategy.
+	fn resample_with_settings(&mut self, channel_count:usize, sample_rate:u32, quality:ResampleQuality, overflow:OverflowStrategy) {
+		if channel_count == self.channel_count {
+			self.change_sample_rate(sample_rate, quality);
+			return;
+		}
+		let target_layout:Option<ChannelLayout> = self.channel_layout.as_ref().and(ChannelLayout::default_for_channel_count(channel_count));
+		let matrix:ChannelMatrix = match (&self.channel_layout, &target_layout) {
+			(Some(source_layout), Some(target_layout)) => ChannelMatrix::standard(source_layout, target_layout),
+			_ => ChannelMatrix::by_count(self.channel_count, channel_count)
+		};
+		self.resample_with_matrix(&matrix, target_layout, sample_rate, quality, overflow);
+	}
+
+	/// Return the audio-buffer resampled to the given channel layout.
+	pub fn resampled_to_layout(mut self, channel_layout:ChannelLayout, sample_rate:u32) -> Self {
+		self.resample_to_layout(channel_layout, sample_rate);
+		self
+	}
+
+	/// Resample the audio to the given channel layout. If the current layout is known, the channels are converted using the standard matrix between the layouts. Otherwise the channels are converted by count.
+	pub fn resample_to_layout(&mut self, channel_layout:ChannelLayout, sample_rate:u32) {
+		let matrix:ChannelMatrix = match &self.channel_layout {
+			Some(source_layout) => ChannelMatrix::standard(source_layout, &channel_layout),
+			None => ChannelMatrix::by_count(self.channel_count, channel_layout.channel_count())
+		};
+		self.resample_with_matrix(&matrix, Some(channel_layout), sample_rate, ResampleQuality::default(), OverflowStrategy::default());
 	}
 
-	/// Grow the sample rate of this sample quickly, but slightly inaccurately.
-	fn grow_sample_rate_fast(&mut self, new_sample_rate:u32) {
-		let current_sample_count:usize = self.data.len();
-		let channel_count:usize = self.channel_count;
-
-		let new_sample_count:usize = (current_sample_count as f32 / self.sample_rate as f32 * new_sample_rate as f32) as usize;
-		let samples_to_insert:usize = new_sample_count - current_sample_count;
-		let insert_one_sample_every:usize = current_sample_count / samples_to_insert; // Make sure to insert sample in each channel.
-		
-		let mut insert_index:usize = 1;
-		for _ in 0..samples_to_insert {
-			let new_data:Vec<f32> = self.data.iter().skip(insert_index).take(channel_count).cloned().collect();
-			self.data.splice(insert_index..insert_index, new_data);
-			insert_index += insert_one_sample_every + channel_count; // Take inserted sample s into account.
+	/// Resample the audio, converting the channels with the given matrix. Channels are removed before and added after changing the sample rate, so the least amount of channels is resampled. Downmixed samples outside of the range -1.0 to 1.0 are handled using the overflow strategy.
+	fn resample_with_matrix(&mut self, matrix:&ChannelMatrix, channel_layout:Option<ChannelLayout>, sample_rate:u32, quality:ResampleQuality, overflow:OverflowStrategy) {
+		if matrix.target_channel_count() < self.channel_count {
+			self.data = matrix.apply_interleaved(&self.data);
+			self.channel_count = matrix.target_channel_count();
+			overflow.apply(&mut self.data, self.channel_count, self.sample_rate);
+			self.change_sample_rate(sample_rate, quality);
+		} else {
+			self.change_sample_rate(sample_rate, quality);
+			self.data = matrix.apply_interleaved(&self.data);
+			self.channel_count = matrix.target_channel_count();
 		}
+		self.channel_layout = channel_layout;
+	}
 
+	/// Change the sample rate of this sample, interpolating the data using the given quality. The loop range is moved to the same positions in time.
+	fn change_sample_rate(&mut self, new_sample_rate:u32, quality:ResampleQuality) {
+		if new_sample_rate == self.sample_rate {
+			return;
+		}
+		self.data = resample_interleaved(&self.data, self.channel_count, self.sample_rate, new_sample_rate, quality);
+		if let Some(loop_range) = &mut self.loop_range {
+			*loop_range = resampled_frame_count(loop_range.start, self.sample_rate, new_sample_rate)..resampled_frame_count(loop_range.end, self.sample_rate, new_sample_rate);
+		}
 		self.sample_rate = new_sample_rate;
 	}
 }
\ No newline at end of file
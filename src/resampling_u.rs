@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test {
-	use std::f32::consts::PI;
-	use crate::AudioBuffer;
+	use std::f32::consts::{ FRAC_1_SQRT_2, PI };
+	use crate::{ AudioBuffer, AudioBufferError, ChannelLayout, ChannelMatrix };
 
 
 
@@ -42,10 +42,7 @@ mod test {
 				original_wave.data.iter().skip(index * 5).take(5).collect::<Vec<&f32>>(),
 				vec![&single_channel_wave.data[index]; 5]
 			);
-			assert_eq!(
-				resampled_wave.data.iter().skip(index * 2).take(2).collect::<Vec<&f32>>(),
-				vec![&single_channel_wave.data[index]; 2]
-			);
+			assert!(resampled_wave.data.iter().skip(index * 2).take(2).all(|sample| (sample - single_channel_wave.data[index]).abs() < 0.00001));
 		}
 	}
 
@@ -65,11 +62,69 @@ mod test {
 	fn decrease_sample_rate() {
 		let original_wave:AudioBuffer = debug_wave(800, 1, 100);
 		let resampled_wave:AudioBuffer = original_wave.clone().resampled(1, 50);
-		for index in 0..400 {
-			assert_eq!(
-				original_wave.data[index * 2],
-				resampled_wave.data[index]
-			);
+		let expected_wave:AudioBuffer = AudioBuffer::new((0..400).map(|index| original_wave.data[index * 2]).collect(), 1, 50);
+		resampled_wave.assert_similar(&expected_wave, 0.0001);
+	}
+
+	#[test]
+	fn try_resample_invalid_target() {
+		let mut wave:AudioBuffer = debug_wave(800, 1, 100);
+		assert_eq!(wave.try_resample(0, 100), Err(AudioBufferError::NoChannels));
+		assert_eq!(wave.try_resample(1, 0), Err(AudioBufferError::NoSampleRate));
+	}
+
+	#[test]
+	fn try_resample_invalid_source() {
+		let wave:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 0, 100);
+		assert_eq!(wave.try_resampled(2, 100), Err(AudioBufferError::NoChannels));
+	}
+
+	#[test]
+	fn resample_to_layout_by_speaker() {
+		let original_wave:AudioBuffer = AudioBuffer::new((0..100).flat_map(|_| [0.1, 0.2, 0.3, 0.4, 0.5, 0.6]).collect(), 6, 100).with_channel_layout(ChannelLayout::Surround51);
+		let resampled_wave:AudioBuffer = original_wave.resampled_to_layout(ChannelLayout::Lcr, 100);
+		assert_eq!(resampled_wave.channel_layout(), Some(&ChannelLayout::Lcr));
+		resampled_wave.assert_similar(&AudioBuffer::new((0..100).flat_map(|_| [0.1 + 0.5 * FRAC_1_SQRT_2, 0.2 + 0.6 * FRAC_1_SQRT_2, 0.3]).collect(), 3, 100), 0.0001);
+	}
+
+	#[test]
+	fn resample_to_layout_without_source_layout() {
+		let original_wave:AudioBuffer = debug_wave(800, 1, 100);
+		let resampled_wave:AudioBuffer = original_wave.clone().resampled_to_layout(ChannelLayout::Stereo, 100);
+		assert_eq!(resampled_wave, original_wave.resampled(2, 100).with_channel_layout(ChannelLayout::Stereo));
+	}
+
+	#[test]
+	fn resample_keeps_known_layout() {
+		let original_wave:AudioBuffer = debug_wave(800, 2, 100).with_channel_layout(ChannelLayout::Stereo);
+		assert_eq!(original_wave.clone().resampled(2, 50).channel_layout(), Some(&ChannelLayout::Stereo));
+		assert_eq!(original_wave.clone().resampled(1, 100).channel_layout(), Some(&ChannelLayout::Mono));
+		assert_eq!(original_wave.resampled(5, 100).channel_layout(), None);
+	}
+
+	#[test]
+	fn resample_uses_standard_matrix() {
+		let original_wave:AudioBuffer = AudioBuffer::new((0..100).flat_map(|_| [0.1, 0.2, 0.3, 0.04, 0.05, 0.06]).collect(), 6, 100).with_channel_layout(ChannelLayout::Surround51);
+		let expected_wave:AudioBuffer = original_wave.clone().remixed(&ChannelMatrix::standard(&ChannelLayout::Surround51, &ChannelLayout::Stereo)).unwrap();
+		assert_eq!(original_wave.resampled(2, 100), expected_wave);
+	}
+
+	#[test]
+	fn decrease_channel_count_folds_channels() {
+		let original_wave:AudioBuffer = AudioBuffer::new((0..100).flat_map(|_| [0.1, 0.2, 0.3, 0.4, 0.5]).collect(), 5, 100);
+		original_wave.resampled(3, 100).assert_similar(&AudioBuffer::new((0..100).flat_map(|_| [0.25, 0.35, 0.3]).collect(), 3, 100), 0.0001);
+
+		// Full-scale surround is hard-clipped by default.
+		let loud_wave:AudioBuffer = AudioBuffer::new(vec![1.0; 600], 6, 100).with_channel_layout(ChannelLayout::Surround51);
+		let downmixed:AudioBuffer = loud_wave.resampled(2, 100);
+		assert!(downmixed.data.iter().all(|sample| *sample == 1.0));
+	}
+
+	#[test]
+	fn try_resample_large_downsampling() {
+		for (channel_count, sample_rate) in [(2, 40), (2, 25), (2, 10), (3, 7), (1, 1)] {
+			let resampled:AudioBuffer = debug_wave(800, channel_count, 100).try_resampled(channel_count, sample_rate).unwrap();
+			assert_eq!(resampled.frame_count(), 800 * sample_rate as usize / 100);
 		}
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,96 @@
+use crate::{ AudioBuffer, analysis::{ decibels_to_gain, windowed_rms } };
+use std::{ ops::Range, time::Duration };
+
+
+
+const DEFAULT_RMS_WINDOW:Duration = Duration::from_millis(10);
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SilenceSettings {
+	threshold_dbfs:f32,
+	min_duration:Duration,
+	rms_window:Duration
+}
+impl SilenceSettings {
+
+	/// Create silence settings. Audio is silent where the windowed RMS stays at or below the threshold in decibels relative to full scale for at least the minimum duration.
+	pub fn new(threshold_dbfs:f32, min_duration:Duration) -> SilenceSettings {
+		SilenceSettings {
+			threshold_dbfs,
+			min_duration,
+			rms_window: DEFAULT_RMS_WINDOW
+		}
+	}
+
+	/// Return the settings with the given RMS measuring window.
+	pub fn with_rms_window(mut self, rms_window:Duration) -> Self {
+		self.rms_window = rms_window;
+		self
+	}
+
+	/// Get the threshold in decibels relative to full scale.
+	pub fn threshold_dbfs(&self) -> f32 {
+		self.threshold_dbfs
+	}
+
+	/// Get the minimum duration of silent regions.
+	pub fn min_duration(&self) -> Duration {
+		self.min_duration
+	}
+
+	/// Get the RMS measuring window.
+	pub fn rms_window(&self) -> Duration {
+		self.rms_window
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Find all silent regions in the audio.
+	pub fn silent_regions(&self, settings:&SilenceSettings) -> Vec<Range<Duration>> {
+		self.silent_frame_ranges(settings).into_iter().map(|range| self.frames_to_duration(range.start)..self.frames_to_duration(range.end)).collect()
+	}
+
+	/// Find the frame ranges of all silent regions in the audio.
+	pub fn silent_frame_ranges(&self, settings:&SilenceSettings) -> Vec<Range<usize>> {
+		let threshold:f32 = decibels_to_gain(settings.threshold_dbfs);
+		let min_frame_count:usize = self.duration_to_frames(settings.min_duration).max(1);
+		let rms_values:Vec<f32> = windowed_rms(&self.data, self.channel_count, self.duration_to_frames(settings.rms_window));
+
+		let mut ranges:Vec<Range<usize>> = Vec::new();
+		let mut range_start:Option<usize> = None;
+		for (frame_index, rms) in rms_values.iter().enumerate() {
+			match (*rms <= threshold, range_start) {
+				(true, None) => range_start = Some(frame_index),
+				(false, Some(start)) => {
+					if frame_index - start >= min_frame_count {
+						ranges.push(start..frame_index);
+					}
+					range_start = None;
+				},
+				_ => {}
+			}
+		}
+		if let Some(start) = range_start.filter(|start| rms_values.len() - start >= min_frame_count) {
+			ranges.push(start..rms_values.len());
+		}
+		ranges
+	}
+
+	/// Split the audio into separate buffers at every silent region. Leading and trailing silence is dropped. Each segment keeps the given padding of the surrounding audio on both sides, as far as available.
+	pub fn split_on_silence(&self, settings:&SilenceSettings, padding:Duration) -> Vec<AudioBuffer> {
+		let padding_frame_count:usize = self.duration_to_frames(padding);
+		let frame_count:usize = self.frame_count();
+		let silent_ranges:Vec<Range<usize>> = self.silent_frame_ranges(settings);
+
+		// Segments lie between the silent regions.
+		let boundaries:Vec<usize> = [0].into_iter().chain(silent_ranges.iter().flat_map(|range| [range.start, range.end])).chain([frame_count]).collect();
+		boundaries.chunks(2).filter(|segment| segment[1] > segment[0]).map(|segment| {
+			self.slice_frames(segment[0].saturating_sub(padding_frame_count)..(segment[1] + padding_frame_count).min(frame_count))
+		}).collect()
+	}
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, SilenceSettings };
+
+
+
+	/// Create a buffer at 1000Hz that alternates between loud and silent parts of the given lengths in frames.
+	fn sections(lengths:&[usize]) -> AudioBuffer {
+		AudioBuffer::new(lengths.iter().enumerate().flat_map(|(index, length)| vec![if index % 2 == 0 { 0.5 } else { 0.0 }; *length]).collect(), 1, 1000)
+	}
+
+
+
+	#[test]
+	fn silent_regions() {
+		let buffer:AudioBuffer = sections(&[100, 200, 100, 50, 100, 300]);
+		let settings:SilenceSettings = SilenceSettings::new(-40.0, Duration::from_millis(100)).with_rms_window(Duration::from_millis(1));
+		assert_eq!(buffer.silent_frame_ranges(&settings), vec![100..300, 550..850]);
+		assert_eq!(buffer.silent_regions(&settings), vec![Duration::from_millis(100)..Duration::from_millis(300), Duration::from_millis(550)..Duration::from_millis(850)]);
+	}
+
+	#[test]
+	fn silent_regions_use_rms_window() {
+		let buffer:AudioBuffer = sections(&[100, 200, 100]);
+		let settings:SilenceSettings = SilenceSettings::new(-40.0, Duration::from_millis(100)).with_rms_window(Duration::from_millis(21));
+		assert_eq!(buffer.silent_frame_ranges(&settings), vec![110..290]);
+	}
+
+	#[test]
+	fn silent_regions_of_quiet_noise() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..1000).map(|index| if index % 2 == 0 { 0.001 } else { -0.001 }).collect(), 2, 1000);
+		assert_eq!(buffer.silent_frame_ranges(&SilenceSettings::new(-50.0, Duration::from_millis(100))), vec![0..500]);
+		assert!(buffer.silent_frame_ranges(&SilenceSettings::new(-70.0, Duration::from_millis(100))).is_empty());
+	}
+
+	#[test]
+	fn split_on_silence() {
+		let buffer:AudioBuffer = sections(&[0, 150, 100, 200, 100, 50, 100, 300]);
+		let settings:SilenceSettings = SilenceSettings::new(-40.0, Duration::from_millis(100)).with_rms_window(Duration::from_millis(1));
+		let takes:Vec<AudioBuffer> = buffer.split_on_silence(&settings, Duration::from_millis(20));
+		assert_eq!(takes.len(), 2);
+		assert_eq!(takes[0].data, [vec![0.0; 20], vec![0.5; 100], vec![0.0; 20]].concat());
+		assert_eq!(takes[1].data, [vec![0.0; 20], vec![0.5; 100], vec![0.0; 50], vec![0.5; 100], vec![0.0; 20]].concat());
+	}
+
+	#[test]
+	fn split_without_silence() {
+		let buffer:AudioBuffer = sections(&[100]);
+		let takes:Vec<AudioBuffer> = buffer.split_on_silence(&SilenceSettings::new(-40.0, Duration::from_millis(100)), Duration::from_millis(20));
+		assert_eq!(takes, vec![buffer]);
+		assert!(AudioBuffer::new(vec![0.0; 100], 1, 100).split_on_silence(&SilenceSettings::new(-40.0, Duration::from_millis(100)), Duration::ZERO).is_empty());
+	}
+}
@@ -0,0 +1,26 @@
+use crate::AudioBuffer;
+use std::f32::consts::PI;
+
+
+
+/// Create a full-scale sine wave with the same samples in every channel.
+pub(crate) fn sine_wave(frequency:f32, frame_count:usize, channel_count:usize, sample_rate:u32) -> AudioBuffer {
+	AudioBuffer::new(
+		(0..frame_count * channel_count).map(|index| ((index / channel_count) as f32 * frequency * 2.0 * PI / sample_rate as f32).sin()).collect(),
+		channel_count,
+		sample_rate
+	)
+}
+
+/// Create a mono sine wave at half scale, leaving headroom for processing.
+pub(crate) fn mono_sine_wave(frequency:f32, frame_count:usize, sample_rate:u32) -> AudioBuffer {
+	AudioBuffer::new((0..frame_count).map(|index| (index as f32 * frequency * 2.0 * PI / sample_rate as f32).sin() * 0.5).collect(), 1, sample_rate)
+}
+
+/// Estimate the frequency of the middle half of a mono buffer by counting rising zero-crossings.
+pub(crate) fn estimated_frequency(buffer:&AudioBuffer) -> f32 {
+	let start:usize = buffer.data.len() / 4;
+	let end:usize = buffer.data.len() * 3 / 4;
+	let crossings:usize = buffer.data[start..end].windows(2).filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0).count();
+	crossings as f32 / ((end - start) as f32 / buffer.sample_rate as f32)
+}
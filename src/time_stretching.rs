@@ -0,0 +1,221 @@
+use crate::{ AudioBuffer, fft::{ Complex, fft, hann_window } };
+use std::{ f64::consts::PI, time::Duration };
+
+
+
+const WSOLA_WINDOW_SECONDS:f64 = 0.03;
+const WSOLA_TOLERANCE_SECONDS:f64 = 0.01;
+const PHASE_VOCODER_WINDOW_SECONDS:f64 = 0.04;
+const PHASE_VOCODER_MIN_WINDOW_SIZE:usize = 256;
+const PHASE_VOCODER_OVERLAP:usize = 4;
+const MIN_WINDOW_SUM:f64 = 0.000001;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum TimeStretchMode {
+	/// Waveform-similarity overlap-add. Splices short segments of the original audio at the most similar positions, which keeps transients and voices natural.
+	#[default]
+	Wsola,
+
+	/// Phase vocoder. Stretches the spectrum frame by frame, which keeps sustained and polyphonic material smooth.
+	PhaseVocoder
+}
+
+
+
+impl AudioBuffer {
+
+	/// Return the audio-buffer time-stretched by the given ratio without changing the pitch. A ratio of 2.0 doubles the duration.
+	pub fn time_stretched(mut self, ratio:f32, mode:TimeStretchMode) -> Self {
+		self.time_stretch(ratio, mode);
+		self
+	}
+
+	/// Time-stretch the audio by the given ratio without changing the pitch. A ratio of 2.0 doubles the duration. The channel count and sample rate are kept.
+	pub fn time_stretch(&mut self, ratio:f32, mode:TimeStretchMode) {
+		let target_frame_count:usize = (self.frame_count() as f64 * ratio.max(0.0) as f64).round() as usize;
+		self.time_stretch_to_frame_count(target_frame_count, mode);
+	}
+
+	/// Return the audio-buffer time-stretched to the given duration without changing the pitch.
+	pub fn time_stretched_to(mut self, duration:Duration, mode:TimeStretchMode) -> Self {
+		self.time_stretch_to(duration, mode);
+		self
+	}
+
+	/// Time-stretch the audio to the given duration without changing the pitch. The channel count and sample rate are kept.
+	pub fn time_stretch_to(&mut self, duration:Duration, mode:TimeStretchMode) {
+		let target_frame_count:usize = self.duration_to_frames(duration);
+		self.time_stretch_to_frame_count(target_frame_count, mode);
+	}
+
+	/// Time-stretch the audio to the given amount of frames without changing the pitch.
+	fn time_stretch_to_frame_count(&mut self, target_frame_count:usize, mode:TimeStretchMode) {
+		let source_frame_count:usize = self.frame_count();
+		if target_frame_count == source_frame_count {
+			return;
+		}
+		self.data = time_stretch_interleaved(&self.data, self.channel_count, self.sample_rate, target_frame_count, mode);
+		self.scale_loop_range(source_frame_count);
+	}
+}
+
+
+
+/// Time-stretch interleaved data to the given amount of frames without changing the pitch. All channels are stretched with the same splice points and phase adjustments, so the relation between channels is kept.
+pub(crate) fn time_stretch_interleaved(data:&[f32], channel_count:usize, sample_rate:u32, target_frame_count:usize, mode:TimeStretchMode) -> Vec<f32> {
+	let source_frame_count:usize = data.len() / channel_count;
+	if source_frame_count == 0 || target_frame_count == 0 {
+		return vec![0.0; target_frame_count * channel_count];
+	}
+	let ratio:f64 = target_frame_count as f64 / source_frame_count as f64;
+	match mode {
+		TimeStretchMode::Wsola => stretch_wsola(data, channel_count, sample_rate, ratio, target_frame_count),
+		TimeStretchMode::PhaseVocoder => stretch_phase_vocoder(data, channel_count, sample_rate, ratio, target_frame_count)
+	}
+}
+
+/// Stretch interleaved data using waveform-similarity overlap-add. The splice positions are searched on the sum of all channels.
+fn stretch_wsola(data:&[f32], channel_count:usize, sample_rate:u32, ratio:f64, target_frame_count:usize) -> Vec<f32> {
+	let window_size:usize = ((sample_rate as f64 * WSOLA_WINDOW_SECONDS) as usize / 2 * 2).max(4);
+	let half_window_size:usize = window_size / 2;
+	let tolerance:isize = ((sample_rate as f64 * WSOLA_TOLERANCE_SECONDS) as usize).min(half_window_size) as isize;
+	let window:Vec<f64> = hann_window(window_size);
+	let guide:Vec<f64> = data.chunks(channel_count).map(|frame| frame.iter().map(|sample| *sample as f64).sum()).collect();
+	let guide_at = |frame_index:isize| if frame_index >= 0 && (frame_index as usize) < guide.len() { guide[frame_index as usize] } else { 0.0 };
+	let source_hop:f64 = half_window_size as f64 / ratio;
+
+	// Overlap-add segments, each centered around its synthesis position.
+	let segment_count:usize = target_frame_count / half_window_size + 2;
+	let mut output:Vec<f64> = vec![0.0; (segment_count * half_window_size + window_size) * channel_count];
+	let mut window_sums:Vec<f64> = vec![0.0; segment_count * half_window_size + window_size];
+	let mut previous_start:isize = -(half_window_size as isize);
+	for segment_index in 0..segment_count {
+		let nominal_start:isize = (segment_index as f64 * source_hop).round() as isize - half_window_size as isize;
+		let start:isize = if segment_index == 0 {
+			nominal_start
+		} else {
+
+			// Find the segment that continues the previous segment most naturally.
+			let natural_start:isize = previous_start + half_window_size as isize;
+			let mut best_start:isize = nominal_start;
+			let mut best_similarity:f64 = f64::MIN;
+			for candidate_start in nominal_start - tolerance..=nominal_start + tolerance {
+				let mut correlation:f64 = 0.0;
+				let mut energy:f64 = 0.0;
+				for offset in 0..half_window_size as isize {
+					let candidate:f64 = guide_at(candidate_start + offset);
+					correlation += candidate * guide_at(natural_start + offset);
+					energy += candidate * candidate;
+				}
+				let similarity:f64 = if energy > 0.0 { correlation / energy.sqrt() } else { 0.0 };
+				if similarity > best_similarity {
+					best_similarity = similarity;
+					best_start = candidate_start;
+				}
+			}
+			best_start
+		};
+		previous_start = start;
+
+		let output_start:usize = segment_index * half_window_size;
+		for (offset, weight) in window.iter().enumerate() {
+			let source_frame_index:isize = start + offset as isize;
+			window_sums[output_start + offset] += weight;
+			if source_frame_index < 0 || source_frame_index as usize >= guide.len() {
+				continue;
+			}
+			let source_frame:&[f32] = &data[source_frame_index as usize * channel_count..(source_frame_index as usize + 1) * channel_count];
+			for (output_sample, source_sample) in output[(output_start + offset) * channel_count..].iter_mut().zip(source_frame) {
+				*output_sample += *source_sample as f64 * weight;
+			}
+		}
+	}
+
+	normalize_overlap_add(&output, &window_sums, channel_count, half_window_size, target_frame_count)
+}
+
+/// Stretch interleaved data using a phase vocoder. The phase of every bin is advanced using the channel that is loudest in that bin, and all channels are rotated by the same amount to keep them phase-coherent.
+fn stretch_phase_vocoder(data:&[f32], channel_count:usize, sample_rate:u32, ratio:f64, target_frame_count:usize) -> Vec<f32> {
+	let window_size:usize = ((sample_rate as f64 * PHASE_VOCODER_WINDOW_SECONDS) as usize).next_power_of_two().max(PHASE_VOCODER_MIN_WINDOW_SIZE);
+	let half_window_size:usize = window_size / 2;
+	let target_hop:usize = window_size / PHASE_VOCODER_OVERLAP;
+	let source_hop:f64 = target_hop as f64 / ratio;
+	let window:Vec<f64> = hann_window(window_size);
+	let source_frame_count:isize = (data.len() / channel_count) as isize;
+	let bin_count:usize = half_window_size + 1;
+
+	let frame_count:usize = target_frame_count / target_hop + PHASE_VOCODER_OVERLAP + 1;
+	let mut output:Vec<f64> = vec![0.0; (frame_count * target_hop + window_size) * channel_count];
+	let mut window_sums:Vec<f64> = vec![0.0; frame_count * target_hop + window_size];
+	let mut previous_phases:Vec<Vec<f64>> = vec![vec![0.0; bin_count]; channel_count];
+	let mut synthesis_phases:Vec<f64> = vec![0.0; bin_count];
+	let mut previous_start:isize = 0;
+	let mut spectra:Vec<Vec<Complex>> = vec![vec![Complex::default(); window_size]; channel_count];
+	for frame_index in 0..frame_count {
+		let start:isize = (frame_index as f64 * source_hop).round() as isize - half_window_size as isize;
+		let source_step:isize = start - previous_start;
+		previous_start = start;
+
+		// Analyze all channels.
+		for (channel_index, spectrum) in spectra.iter_mut().enumerate() {
+			for (offset, value) in spectrum.iter_mut().enumerate() {
+				let source_frame_index:isize = start + offset as isize;
+				let sample:f64 = if source_frame_index >= 0 && source_frame_index < source_frame_count { data[source_frame_index as usize * channel_count + channel_index] as f64 } else { 0.0 };
+				*value = Complex::new(sample * window[offset], 0.0);
+			}
+			fft(spectrum, false);
+		}
+
+		// Advance the phase of each bin using the loudest channel in that bin, then rotate all channels by the same amount.
+		for bin_index in 0..bin_count {
+			let guide_channel:usize = (0..channel_count).max_by(|a, b| spectra[*a][bin_index].magnitude().total_cmp(&spectra[*b][bin_index].magnitude())).unwrap_or(0);
+			let guide_phase:f64 = spectra[guide_channel][bin_index].phase();
+			let bin_frequency:f64 = 2.0 * PI * bin_index as f64 / window_size as f64;
+			if frame_index == 0 {
+				synthesis_phases[bin_index] = guide_phase;
+			} else if source_step == 0 {
+				synthesis_phases[bin_index] += bin_frequency * target_hop as f64;
+			} else {
+				let phase_deviation:f64 = wrap_phase(guide_phase - previous_phases[guide_channel][bin_index] - bin_frequency * source_step as f64);
+				let true_frequency:f64 = bin_frequency + phase_deviation / source_step as f64;
+				synthesis_phases[bin_index] = wrap_phase(synthesis_phases[bin_index] + true_frequency * target_hop as f64);
+			}
+			let rotation:Complex = Complex::from_polar(1.0, synthesis_phases[bin_index] - guide_phase);
+			for (channel_index, spectrum) in spectra.iter_mut().enumerate() {
+				previous_phases[channel_index][bin_index] = spectrum[bin_index].phase();
+				spectrum[bin_index] = spectrum[bin_index] * rotation;
+			}
+		}
+
+		// Mirror the spectra, resynthesize and overlap-add.
+		let output_start:usize = frame_index * target_hop;
+		for (channel_index, spectrum) in spectra.iter_mut().enumerate() {
+			for bin_index in 1..half_window_size {
+				spectrum[window_size - bin_index] = Complex::new(spectrum[bin_index].re, -spectrum[bin_index].im);
+			}
+			fft(spectrum, true);
+			for (offset, value) in spectrum.iter().enumerate() {
+				output[(output_start + offset) * channel_count + channel_index] += value.re * window[offset];
+			}
+		}
+		for (offset, weight) in window.iter().enumerate() {
+			window_sums[output_start + offset] += weight * weight;
+		}
+	}
+
+	normalize_overlap_add(&output, &window_sums, channel_count, half_window_size, target_frame_count)
+}
+
+/// Divide overlap-added output by the sum of the windows and remove the padding before the first frame.
+fn normalize_overlap_add(output:&[f64], window_sums:&[f64], channel_count:usize, padding:usize, target_frame_count:usize) -> Vec<f32> {
+	output.chunks(channel_count).zip(window_sums).skip(padding).take(target_frame_count).flat_map(|(frame, window_sum)| {
+		frame.iter().map(move |sample| if *window_sum > MIN_WINDOW_SUM { (sample / window_sum) as f32 } else { 0.0 })
+	}).collect()
+}
+
+/// Wrap a phase to the range -PI to PI.
+fn wrap_phase(phase:f64) -> f64 {
+	phase - 2.0 * PI * (phase / (2.0 * PI)).round()
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, ChannelLayout, TimeStretchMode, test_helpers::{ estimated_frequency, mono_sine_wave } };
+
+
+
+	const MODES:[TimeStretchMode; 2] = [TimeStretchMode::Wsola, TimeStretchMode::PhaseVocoder];
+
+
+
+	#[test]
+	fn time_stretch_changes_length() {
+		for mode in MODES {
+			let wave:AudioBuffer = mono_sine_wave(220.0, 8000, 8000).with_channel_layout(ChannelLayout::Mono);
+			let stretched:AudioBuffer = wave.clone().time_stretched(1.5, mode);
+			assert_eq!(stretched.frame_count(), 12000);
+			assert_eq!(stretched.channel_count, 1);
+			assert_eq!(stretched.sample_rate, 8000);
+			assert_eq!(stretched.channel_layout(), Some(&ChannelLayout::Mono));
+			assert_eq!(wave.time_stretched(0.5, mode).frame_count(), 4000);
+		}
+	}
+
+	#[test]
+	fn time_stretch_keeps_pitch() {
+		for mode in MODES {
+			for ratio in [0.75, 1.5] {
+				let stretched:AudioBuffer = mono_sine_wave(440.0, 8000, 8000).time_stretched(ratio, mode);
+				assert!((estimated_frequency(&stretched) - 440.0).abs() < 440.0 * 0.02);
+			}
+		}
+	}
+
+	#[test]
+	fn time_stretch_keeps_level() {
+		for mode in MODES {
+			let stretched:AudioBuffer = mono_sine_wave(300.0, 8000, 8000).time_stretched(1.25, mode);
+			let middle:AudioBuffer = stretched.slice_frames(2000..8000);
+			assert!((middle.rms() - 0.5 / 2.0_f32.sqrt()).abs() < 0.05);
+		}
+	}
+
+	#[test]
+	fn time_stretch_keeps_channels_coherent() {
+		for mode in MODES {
+			let wave:AudioBuffer = mono_sine_wave(330.0, 4000, 8000);
+			let stereo:AudioBuffer = AudioBuffer::new(wave.data.iter().flat_map(|sample| [*sample, -*sample]).collect(), 2, 8000);
+			let stretched:AudioBuffer = stereo.time_stretched(1.3, mode);
+			assert_eq!(stretched.channel_count, 2);
+			for frame in stretched.frames() {
+				assert!((frame[0] + frame[1]).abs() < 0.0001);
+			}
+		}
+	}
+
+	#[test]
+	fn time_stretch_to_duration() {
+		let stretched:AudioBuffer = mono_sine_wave(220.0, 8000, 8000).time_stretched_to(Duration::from_millis(1250), TimeStretchMode::Wsola);
+		assert_eq!(stretched.frame_count(), 10000);
+	}
+
+	#[test]
+	fn time_stretch_empty() {
+		for mode in MODES {
+			let stretched:AudioBuffer = AudioBuffer::new(Vec::new(), 2, 8000).time_stretched(2.0, mode);
+			assert!(stretched.data.is_empty());
+		}
+	}
+}
@@ -0,0 +1,252 @@
+use crate::{ AudioBuffer, AudioBufferAddition, AudioBufferError, FadeCurve, OverflowStrategy, PlacedAddition, analysis::decibels_to_gain, fade::{ fade_in_interleaved, fade_out_interleaved, fade_out_to_silence_interleaved } };
+use std::{ sync::Arc, time::Duration };
+
+
+
+#[derive(Clone)]
+pub struct Clip {
+	source:Arc<AudioBuffer>,
+	source_start:Duration,
+	source_end:Duration,
+	position:Duration,
+	gain_db:f32,
+	fade_in:(Duration, FadeCurve),
+	fade_out:(Duration, FadeCurve)
+}
+impl Clip {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a clip that plays the whole source at the given timeline position.
+	pub fn new(source:Arc<AudioBuffer>, position:Duration) -> Clip {
+		Clip {
+			source_start: Duration::ZERO,
+			source_end: source.duration(),
+			source,
+			position,
+			gain_db: 0.0,
+			fade_in: (Duration::ZERO, FadeCurve::default()),
+			fade_out: (Duration::ZERO, FadeCurve::default())
+		}
+	}
+
+	/// Return the clip playing only the given range of the source.
+	pub fn with_source_range(mut self, source_start:Duration, source_end:Duration) -> Self {
+		self.source_start = source_start.min(self.source.duration());
+		self.source_end = source_end.clamp(self.source_start, self.source.duration());
+		self
+	}
+
+	/// Return the clip with the given gain in decibels.
+	pub fn with_gain_db(mut self, gain_db:f32) -> Self {
+		self.gain_db = gain_db;
+		self
+	}
+
+	/// Return the clip with a fade-in of the given duration and curve.
+	pub fn with_fade_in(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.fade_in = (duration, curve);
+		self
+	}
+
+	/// Return the clip with a fade-out of the given duration and curve.
+	pub fn with_fade_out(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.fade_out = (duration, curve);
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the source audio of the clip.
+	pub fn source(&self) -> &Arc<AudioBuffer> {
+		&self.source
+	}
+
+	/// Get the start of the played range in the source.
+	pub fn source_start(&self) -> Duration {
+		self.source_start
+	}
+
+	/// Get the end of the played range in the source.
+	pub fn source_end(&self) -> Duration {
+		self.source_end
+	}
+
+	/// Get the position of the clip on the timeline.
+	pub fn position(&self) -> Duration {
+		self.position
+	}
+
+	/// Get the end of the clip on the timeline.
+	pub fn end(&self) -> Duration {
+		self.position + (self.source_end - self.source_start)
+	}
+
+	/// Get the gain of the clip in decibels.
+	pub fn gain_db(&self) -> f32 {
+		self.gain_db
+	}
+
+	/// Get the duration and curve of the fade-in.
+	pub fn fade_in(&self) -> (Duration, FadeCurve) {
+		self.fade_in
+	}
+
+	/// Get the duration and curve of the fade-out.
+	pub fn fade_out(&self) -> (Duration, FadeCurve) {
+		self.fade_out
+	}
+
+
+
+	/* RENDERING METHODS */
+
+	/// Render the played range of the source with gain applied at the given channel count and sample rate. Returns the timeline frame position and the data.
+	fn render(&self, channel_count:usize, sample_rate:u32) -> (usize, Vec<f32>) {
+		let source_frames:AudioBuffer = self.source.slice_time(self.source_start..self.source_end);
+		let mut data:Vec<f32> = source_frames.as_raw_list(channel_count, sample_rate).into_iter().flatten().collect();
+		let gain:f32 = decibels_to_gain(self.gain_db);
+		if gain != 1.0 {
+			data.iter_mut().for_each(|sample| *sample *= gain);
+		}
+		((self.position.as_secs_f64() * sample_rate as f64).round() as usize, data)
+	}
+}
+
+
+
+pub struct Timeline {
+	channel_count:usize,
+	sample_rate:u32,
+	lanes:Vec<Vec<Clip>>,
+	crossfade_curve:FadeCurve,
+	overflow:OverflowStrategy
+}
+impl Timeline {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create an empty timeline that renders at the given channel count and sample rate. Rendering panics if either is zero, use `try_new` to check them up front.
+	pub fn new(channel_count:usize, sample_rate:u32) -> Timeline {
+		Timeline {
+			channel_count,
+			sample_rate,
+			lanes: Vec::new(),
+			crossfade_curve: FadeCurve::EqualPower,
+			overflow: OverflowStrategy::default()
+		}
+	}
+
+	/// Try to create an empty timeline. Returns an error if the channel count or sample rate is zero.
+	pub fn try_new(channel_count:usize, sample_rate:u32) -> Result<Timeline, AudioBufferError> {
+		AudioBuffer::validate_settings(channel_count, sample_rate)?;
+		Ok(Timeline::new(channel_count, sample_rate))
+	}
+
+	/// Return the timeline with the given curve for automatic crossfades.
+	pub fn with_crossfade_curve(mut self, curve:FadeCurve) -> Self {
+		self.crossfade_curve = curve;
+		self
+	}
+
+	/// Return the timeline with the given overflow strategy for the rendered mix.
+	pub fn with_overflow(mut self, overflow:OverflowStrategy) -> Self {
+		self.overflow = overflow;
+		self
+	}
+
+
+
+	/* CLIP METHODS */
+
+	/// Add a clip to the given lane. Lanes are created as needed.
+	pub fn add_clip(&mut self, lane_index:usize, clip:Clip) {
+		if lane_index >= self.lanes.len() {
+			self.lanes.resize_with(lane_index + 1, Vec::new);
+		}
+		self.lanes[lane_index].push(clip);
+	}
+
+	/// Remove a clip from a lane.
+	pub fn remove_clip(&mut self, lane_index:usize, clip_index:usize) -> Option<Clip> {
+		self.lanes.get_mut(lane_index).filter(|lane| clip_index < lane.len()).map(|lane| lane.remove(clip_index))
+	}
+
+	/// Get the clips of a lane in the order they were added.
+	pub fn lane(&self, lane_index:usize) -> &[Clip] {
+		self.lanes.get(lane_index).map(|lane| lane.as_slice()).unwrap_or_default()
+	}
+
+	/// Get the clips of a lane mutably.
+	pub fn lane_mut(&mut self, lane_index:usize) -> Option<&mut Vec<Clip>> {
+		self.lanes.get_mut(lane_index)
+	}
+
+	/// Get the amount of lanes.
+	pub fn lane_count(&self) -> usize {
+		self.lanes.len()
+	}
+
+	/// Get the end of the last clip.
+	pub fn duration(&self) -> Duration {
+		self.lanes.iter().flatten().map(|clip| clip.end()).max().unwrap_or_default()
+	}
+
+
+
+	/* RENDERING METHODS */
+
+	/// Render all lanes into a single buffer. Clips on the same lane that overlap are crossfaded with the next clip over the overlap instead of using their own fades on that side, clips with the same start crossfade over the length of the shorter one. Only two clips crossfade at a time, a clip is cut off where the clip after the next one starts. Clips that start within another clip and end before it are mixed on top of it without crossfading.
+	pub fn render(&self) -> AudioBuffer {
+		let placed_clips:Vec<PlacedAddition<Vec<f32>>> = self.lanes.iter().flat_map(|lane| self.render_lane(lane)).collect();
+		AudioBuffer::new(Vec::new(), self.channel_count, self.sample_rate).combined_with_overflow(placed_clips, self.overflow)
+	}
+
+	/// Render the clips of a lane with their fades and crossfades applied.
+	fn render_lane(&self, lane:&[Clip]) -> Vec<PlacedAddition<Vec<f32>>> {
+		let mut rendered:Vec<(usize, Vec<f32>)> = lane.iter().map(|clip| clip.render(self.channel_count, self.sample_rate)).collect();
+		let frame_count = |duration:Duration| (duration.as_secs_f64() * self.sample_rate as f64).round() as usize;
+
+		// Order the clips that are not nested within another clip, so each one only crossfades with the next one.
+		let spans:Vec<(usize, usize)> = rendered.iter().map(|(start, data)| (*start, start + data.len() / self.channel_count)).collect();
+		let mut chain:Vec<usize> = (0..spans.len()).filter(|clip_index| {
+			let (start, end) = spans[*clip_index];
+			!spans.iter().any(|(other_start, other_end)| *other_start < start && end <= *other_end)
+		}).collect();
+		chain.sort_by_key(|clip_index| spans[*clip_index]);
+
+		// Cut clips off where the clip after the next one starts, then crossfade each pair over their overlap.
+		let mut crossfade_in_frame_counts:Vec<usize> = vec![0; spans.len()];
+		let mut crossfade_out_frame_counts:Vec<usize> = vec![0; spans.len()];
+		for (chain_index, clip_index) in chain.iter().enumerate() {
+			let (start, mut end) = spans[*clip_index];
+			if let Some(after_next_index) = chain.get(chain_index + 2) && spans[*after_next_index].0 < end {
+				end = spans[*after_next_index].0;
+				rendered[*clip_index].1.truncate((end - start) * self.channel_count);
+			}
+			if let Some(next_index) = chain.get(chain_index + 1) && spans[*next_index].0 < end {
+				let overlap:usize = end - spans[*next_index].0;
+				crossfade_out_frame_counts[*clip_index] = overlap;
+				crossfade_in_frame_counts[*next_index] = overlap;
+			}
+		}
+
+		rendered.into_iter().enumerate().map(|(clip_index, (start, mut data))| {
+			let crossfade_in:usize = crossfade_in_frame_counts[clip_index];
+			let crossfade_out:usize = crossfade_out_frame_counts[clip_index];
+			let (fade_in_duration, fade_in_curve) = lane[clip_index].fade_in;
+			let (fade_out_duration, fade_out_curve) = lane[clip_index].fade_out;
+			match crossfade_in {
+				0 => fade_in_interleaved(&mut data, self.channel_count, frame_count(fade_in_duration), fade_in_curve),
+				_ => fade_in_interleaved(&mut data, self.channel_count, crossfade_in, self.crossfade_curve)
+			}
+			match crossfade_out {
+				0 => fade_out_to_silence_interleaved(&mut data, self.channel_count, frame_count(fade_out_duration), fade_out_curve),
+				_ => fade_out_interleaved(&mut data, self.channel_count, crossfade_out, self.crossfade_curve)
+			}
+			PlacedAddition::at_frame(data, start as isize)
+		}).collect()
+	}
+}
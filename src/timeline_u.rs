@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod test {
+	use std::{ sync::Arc, time::Duration };
+	use crate::{ AudioBuffer, AudioBufferError, Clip, FadeCurve, OverflowStrategy, Timeline };
+
+
+
+	fn constant(value:f32, frame_count:usize) -> Arc<AudioBuffer> {
+		Arc::new(AudioBuffer::new(vec![value; frame_count], 1, 100))
+	}
+
+
+
+	#[test]
+	fn timeline_places_clips() {
+		let mut timeline:Timeline = Timeline::new(1, 100);
+		timeline.add_clip(0, Clip::new(constant(0.1, 10), Duration::from_millis(50)));
+		timeline.add_clip(2, Clip::new(constant(0.2, 10), Duration::from_millis(100)).with_gain_db(-6.0206));
+		assert_eq!(timeline.lane_count(), 3);
+		assert_eq!(timeline.duration(), Duration::from_millis(200));
+
+		let expected:AudioBuffer = AudioBuffer::new([vec![0.0; 5], vec![0.1; 5], vec![0.2; 5], vec![0.1; 5]].concat(), 1, 100);
+		timeline.render().assert_similar(&expected, 0.0001);
+	}
+
+	#[test]
+	fn timeline_source_range() {
+		let source:Arc<AudioBuffer> = Arc::new(AudioBuffer::new((0..100).map(|index| index as f32 / 100.0).collect(), 1, 100));
+		let mut timeline:Timeline = Timeline::new(1, 100);
+		timeline.add_clip(0, Clip::new(source, Duration::ZERO).with_source_range(Duration::from_millis(200), Duration::from_millis(250)));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.2, 0.21, 0.22, 0.23, 0.24], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn timeline_resamples_clips() {
+		let mut timeline:Timeline = Timeline::new(2, 200);
+		timeline.add_clip(0, Clip::new(constant(0.5, 100), Duration::ZERO));
+		let rendered:AudioBuffer = timeline.render();
+		assert_eq!(rendered.channel_count(), 2);
+		rendered.assert_similar(&AudioBuffer::new(vec![0.5; 400], 2, 200), 0.0001);
+	}
+
+	#[test]
+	fn timeline_clip_fades() {
+		let mut timeline:Timeline = Timeline::new(1, 100);
+		timeline.add_clip(0, Clip::new(constant(1.0, 10), Duration::ZERO).with_fade_in(Duration::from_millis(40), FadeCurve::Linear).with_fade_out(Duration::from_millis(20), FadeCurve::Linear));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn timeline_crossfades_overlaps() {
+		let mut timeline:Timeline = Timeline::new(1, 100).with_crossfade_curve(FadeCurve::Linear).with_overflow(OverflowStrategy::None);
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::from_millis(60)));
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::ZERO).with_fade_out(Duration::from_millis(100), FadeCurve::Linear));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.5; 16], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn timeline_nested_clips_do_not_crossfade() {
+		let mut timeline:Timeline = Timeline::new(1, 100).with_crossfade_curve(FadeCurve::Linear).with_overflow(OverflowStrategy::None);
+		timeline.add_clip(0, Clip::new(constant(0.5, 100), Duration::ZERO));
+		timeline.add_clip(0, Clip::new(constant(0.25, 10), Duration::from_millis(200)));
+		let rendered:AudioBuffer = timeline.render();
+		assert_eq!(rendered.data.len(), 100);
+		assert_eq!(rendered.data[..20], [0.5; 20]);
+		assert_eq!(rendered.data[20..30], [0.75; 10]);
+		assert_eq!(rendered.data[30..], [0.5; 70]);
+	}
+
+	#[test]
+	fn timeline_crossfades_three_way_overlaps() {
+		let mut timeline:Timeline = Timeline::new(1, 100).with_crossfade_curve(FadeCurve::Linear).with_overflow(OverflowStrategy::None);
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::ZERO));
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::from_millis(50)));
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::from_millis(80)));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.5; 18], 1, 100), 0.0001);
+
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::from_millis(90)));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.5; 19], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn timeline_crossfades_same_start() {
+		let mut timeline:Timeline = Timeline::new(1, 100).with_crossfade_curve(FadeCurve::Linear).with_overflow(OverflowStrategy::None);
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::ZERO));
+		timeline.add_clip(0, Clip::new(constant(0.5, 4), Duration::ZERO));
+		timeline.render().assert_similar(&AudioBuffer::new(vec![0.5; 10], 1, 100), 0.0001);
+	}
+
+	#[test]
+	fn timeline_try_new() {
+		assert!(Timeline::try_new(2, 44100).is_ok());
+		assert_eq!(Timeline::try_new(0, 44100).err(), Some(AudioBufferError::NoChannels));
+		assert_eq!(Timeline::try_new(2, 0).err(), Some(AudioBufferError::NoSampleRate));
+	}
+
+	#[test]
+	fn timeline_lanes_do_not_crossfade() {
+		let mut timeline:Timeline = Timeline::new(1, 100).with_overflow(OverflowStrategy::None);
+		timeline.add_clip(0, Clip::new(constant(0.5, 10), Duration::ZERO));
+		timeline.add_clip(1, Clip::new(constant(0.5, 10), Duration::from_millis(50)));
+		let rendered:AudioBuffer = timeline.render();
+		assert_eq!(rendered.data[5..10], [1.0; 5]);
+		assert!(timeline.remove_clip(1, 0).is_some());
+		assert!(timeline.remove_clip(1, 0).is_none());
+		assert_eq!(timeline.render().data, vec![0.5; 10]);
+	}
+}
@@ -0,0 +1,61 @@
+use crate::AudioBuffer;
+use std::time::Duration;
+
+
+
+impl AudioBuffer {
+
+	/// Get the duration of the buffer.
+	pub fn duration(&self) -> Duration {
+		self.frames_to_duration(self.frame_count())
+	}
+
+	/// Get the duration of the buffer in seconds.
+	pub fn duration_seconds(&self) -> f64 {
+		self.frames_to_seconds(self.frame_count())
+	}
+
+
+
+	/* CONVERSION METHODS */
+
+	/// Convert an amount of frames to a duration.
+	pub fn frames_to_duration(&self, frames:usize) -> Duration {
+		Duration::from_secs_f64(self.frames_to_seconds(frames))
+	}
+
+	/// Convert a duration to an amount of frames, rounded to the nearest frame.
+	pub fn duration_to_frames(&self, duration:Duration) -> usize {
+		self.seconds_to_frames(duration.as_secs_f64())
+	}
+
+	/// Convert an amount of frames to seconds.
+	pub fn frames_to_seconds(&self, frames:usize) -> f64 {
+		frames as f64 / self.sample_rate as f64
+	}
+
+	/// Convert seconds to an amount of frames, rounded to the nearest frame. Negative seconds result in zero frames.
+	pub fn seconds_to_frames(&self, seconds:f64) -> usize {
+		(seconds * self.sample_rate as f64).round().max(0.0) as usize
+	}
+
+	/// Convert a frame index to the index of its first sample in the interleaved data.
+	pub fn frame_to_sample_index(&self, frame_index:usize) -> usize {
+		frame_index * self.channel_count
+	}
+
+	/// Convert an index in the interleaved data to the index of the frame it belongs to.
+	pub fn sample_index_to_frame(&self, sample_index:usize) -> usize {
+		sample_index / self.channel_count
+	}
+
+	/// Convert a duration to the index of the first sample of the nearest frame in the interleaved data.
+	pub fn duration_to_sample_index(&self, duration:Duration) -> usize {
+		self.frame_to_sample_index(self.duration_to_frames(duration))
+	}
+
+	/// Convert an index in the interleaved data to the duration at which its frame starts.
+	pub fn sample_index_to_duration(&self, sample_index:usize) -> Duration {
+		self.frames_to_duration(self.sample_index_to_frame(sample_index))
+	}
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod test {
+	use crate::AudioBuffer;
+	use std::time::Duration;
+
+
+
+	#[test]
+	fn duration() {
+		assert_eq!(AudioBuffer::new(vec![0.0; 2000], 2, 1000).duration(), Duration::from_secs(1));
+		assert_eq!(AudioBuffer::new(vec![0.0; 1500], 1, 1000).duration(), Duration::from_millis(1500));
+		assert_eq!(AudioBuffer::new(vec![0.0; 44100 * 3], 3, 44100).duration_seconds(), 1.0);
+	}
+
+	#[test]
+	fn frame_duration_conversion() {
+		let buffer:AudioBuffer = AudioBuffer::new(Vec::new(), 2, 48000);
+		assert_eq!(buffer.duration_to_frames(Duration::from_millis(250)), 12000);
+		assert_eq!(buffer.frames_to_duration(12000), Duration::from_millis(250));
+		assert_eq!(buffer.seconds_to_frames(0.5), 24000);
+		assert_eq!(buffer.seconds_to_frames(-0.5), 0);
+		assert_eq!(buffer.frames_to_seconds(24000), 0.5);
+	}
+
+	#[test]
+	fn sample_index_conversion() {
+		let buffer:AudioBuffer = AudioBuffer::new(Vec::new(), 2, 1000);
+		assert_eq!(buffer.frame_to_sample_index(10), 20);
+		assert_eq!(buffer.sample_index_to_frame(21), 10);
+		assert_eq!(buffer.duration_to_sample_index(Duration::from_millis(10)), 20);
+		assert_eq!(buffer.sample_index_to_duration(21), Duration::from_millis(10));
+	}
+}
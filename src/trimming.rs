@@ -1,4 +1,84 @@
-use crate::AudioBuffer;
+use crate::{ AudioBuffer, AudioBufferError, FadeCurve, analysis::{ decibels_to_gain, is_zero_crossing, windowed_peak, windowed_rms }, fade::{ fade_in_interleaved, fade_out_to_silence_interleaved } };
+use std::time::Duration;
+
+
+
+const DEFAULT_DETECTION_WINDOW:Duration = Duration::from_millis(10);
+const DEFAULT_MIN_DURATION:Duration = Duration::from_millis(20);
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum TrimDetection {
+	/// Detect signal using the root mean square over a window.
+	#[default]
+	Rms,
+
+	/// Detect signal using the highest absolute sample value over a window.
+	Peak
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TrimSettings {
+	threshold_dbfs:f32,
+	detection:TrimDetection,
+	detection_window:Duration,
+	min_duration:Duration,
+	pre_roll:Duration,
+	post_roll:Duration,
+	zero_crossing_window:Option<Duration>,
+	fade:(Duration, FadeCurve)
+}
+impl TrimSettings {
+
+	/// Create trim settings. Signal is detected where the RMS envelope exceeds the threshold in decibels relative to full scale for at least 20ms.
+	pub fn new(threshold_dbfs:f32) -> TrimSettings {
+		TrimSettings {
+			threshold_dbfs,
+			detection: TrimDetection::default(),
+			detection_window: DEFAULT_DETECTION_WINDOW,
+			min_duration: DEFAULT_MIN_DURATION,
+			pre_roll: Duration::ZERO,
+			post_roll: Duration::ZERO,
+			zero_crossing_window: None,
+			fade: (Duration::ZERO, FadeCurve::default())
+		}
+	}
+
+	/// Return the settings with the given envelope detection and window.
+	pub fn with_detection(mut self, detection:TrimDetection, window:Duration) -> Self {
+		self.detection = detection;
+		self.detection_window = window;
+		self
+	}
+
+	/// Return the settings requiring the envelope to stay above the threshold for the given duration before it counts as signal.
+	pub fn with_min_duration(mut self, min_duration:Duration) -> Self {
+		self.min_duration = min_duration;
+		self
+	}
+
+	/// Return the settings keeping the given amount of audio before and after the detected signal.
+	pub fn with_padding(mut self, pre_roll:Duration, post_roll:Duration) -> Self {
+		self.pre_roll = pre_roll;
+		self.post_roll = post_roll;
+		self
+	}
+
+	/// Return the settings moving the cuts outwards to the nearest zero crossing within the given window.
+	pub fn with_zero_crossing_snap(mut self, window:Duration) -> Self {
+		self.zero_crossing_window = Some(window);
+		self
+	}
+
+	/// Return the settings fading in and out at the new edges.
+	pub fn with_fades(mut self, duration:Duration, curve:FadeCurve) -> Self {
+		self.fade = (duration, curve);
+		self
+	}
+}
 
 
 
@@ -10,18 +90,40 @@ impl AudioBuffer {
 		self.trim_start(volume_threshold);
 	}
 
+	/// Trim the quiet start and end off of the buffer. Returns an error instead of panicking if the buffer is invalid.
+	pub fn try_trim(&mut self, volume_threshold:f32) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		self.trim(volume_threshold);
+		Ok(())
+	}
+
+	/// Trim the quiet start off of the buffer. Returns an error instead of panicking if the buffer is invalid.
+	pub fn try_trim_start(&mut self, volume_threshold:f32) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		self.trim_start(volume_threshold);
+		Ok(())
+	}
+
+	/// Trim the quiet end off of the buffer. Returns an error instead of panicking if the buffer is invalid.
+	pub fn try_trim_end(&mut self, volume_threshold:f32) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		self.trim_end(volume_threshold);
+		Ok(())
+	}
+
 	/// Trim the quiet start off of the buffer.
 	pub fn trim_start(&mut self, volume_threshold:f32) {
 		if !self.data.is_empty() {
 			let volume_threshold:f32 = volume_threshold.abs();
 			let mut cursor:usize = 0;
-			for sample_list in self.data.chunks(self.channel_count) {
+			for sample_list in self.frames() {
 				if sample_list.iter().any(|sample| sample.abs() > volume_threshold) {
 					break;
 				} else {
 					cursor += self.channel_count;
 				}
 			}
+			self.replace_loop_frames(0..cursor / self.channel_count, 0);
 			self.data.drain(..cursor);
 		}
 	}
@@ -31,14 +133,94 @@ impl AudioBuffer {
 		if !self.data.is_empty() {
 			let volume_threshold:f32 = volume_threshold.abs();
 			let mut cursor:usize = self.data.len();
-			for sample_list in self.data.chunks(self.channel_count).rev() {
+			for sample_list in self.frames().rev() {
 				if sample_list.iter().any(|sample| sample.abs() > volume_threshold) {
 					break;
 				} else {
 					cursor -= self.channel_count;
 				}
 			}
+			self.replace_loop_frames(cursor / self.channel_count..self.frame_count(), 0);
 			self.data.drain(cursor..);
 		}
 	}
-}
\ No newline at end of file
+
+	/// Return the buffer with the quiet start and end trimmed off using the given settings.
+	pub fn trimmed_with(mut self, settings:&TrimSettings) -> Self {
+		self.trim_with(settings);
+		self
+	}
+
+	/// Return the buffer with the quiet start and end trimmed off using the given settings. Returns an error instead of panicking if the buffer is invalid.
+	pub fn try_trimmed_with(mut self, settings:&TrimSettings) -> Result<Self, AudioBufferError> {
+		self.try_trim_with(settings)?;
+		Ok(self)
+	}
+
+	/// Trim the quiet start and end off of the buffer using the given settings. Returns an error instead of panicking if the buffer is invalid.
+	pub fn try_trim_with(&mut self, settings:&TrimSettings) -> Result<(), AudioBufferError> {
+		self.validate()?;
+		self.trim_with(settings);
+		Ok(())
+	}
+
+	/// Trim the quiet start and end off of the buffer using the given settings. Removes all audio if no signal is detected.
+	pub fn trim_with(&mut self, settings:&TrimSettings) {
+		let channel_count:usize = self.channel_count;
+		let frame_count:usize = self.frame_count();
+		let threshold:f32 = decibels_to_gain(settings.threshold_dbfs);
+		let detection_window:usize = self.duration_to_frames(settings.detection_window);
+		let envelope:Vec<f32> = match settings.detection {
+			TrimDetection::Rms => windowed_rms(&self.data, channel_count, detection_window),
+			TrimDetection::Peak => windowed_peak(&self.data, channel_count, detection_window)
+		};
+
+		// Find the first and last runs above the threshold that last long enough.
+		let min_frame_count:usize = self.duration_to_frames(settings.min_duration).max(1);
+		let mut signal_range:Option<(usize, usize)> = None;
+		let mut run_start:Option<usize> = None;
+		for (frame_index, level) in envelope.iter().chain([&0.0]).enumerate() {
+			match (*level > threshold, run_start) {
+				(true, None) => run_start = Some(frame_index),
+				(false, Some(start)) => {
+					if frame_index - start >= min_frame_count {
+						signal_range = Some((signal_range.map(|(first_start, _)| first_start).unwrap_or(start), frame_index));
+					}
+					run_start = None;
+				},
+				_ => {}
+			}
+		}
+		let (mut start, mut end) = match signal_range {
+			Some(range) => range,
+			None => {
+				self.data.clear();
+				self.loop_range = None;
+				return;
+			}
+		};
+
+		// Add padding and snap to zero crossings.
+		start = start.saturating_sub(self.duration_to_frames(settings.pre_roll));
+		end = (end + self.duration_to_frames(settings.post_roll)).min(frame_count);
+		if let Some(window) = settings.zero_crossing_window {
+			let window_frame_count:usize = self.duration_to_frames(window);
+			if let Some(snapped_start) = (start.saturating_sub(window_frame_count)..=start).rev().find(|frame_index| is_zero_crossing(&self.data, channel_count, *frame_index)) {
+				start = snapped_start;
+			}
+			if let Some(snapped_end) = (end..(end + window_frame_count).min(frame_count)).find(|frame_index| is_zero_crossing(&self.data, channel_count, *frame_index)) {
+				end = snapped_end;
+			}
+		}
+
+		// Cut and fade the new edges.
+		self.replace_loop_frames(end..frame_count, 0);
+		self.replace_loop_frames(0..start, 0);
+		self.data.truncate(end * channel_count);
+		self.data.drain(..start * channel_count);
+		let (fade_duration, fade_curve) = settings.fade;
+		let fade_frame_count:usize = self.duration_to_frames(fade_duration);
+		fade_in_interleaved(&mut self.data, channel_count, fade_frame_count, fade_curve);
+		fade_out_to_silence_interleaved(&mut self.data, channel_count, fade_frame_count, fade_curve);
+	}
+}
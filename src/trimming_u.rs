@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-	use crate::AudioBuffer;
+	use std::time::Duration;
+	use crate::{ AudioBuffer, AudioBufferError, FadeCurve, TrimDetection, TrimSettings };
 
 
 
@@ -87,4 +88,70 @@ mod tests {
 		audio.trim_end(-0.2);
 		assert_eq!(&audio.data, &raw_audio[..90 * 3]);
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn try_trim_misaligned() {
+		let mut audio:AudioBuffer = AudioBuffer::new(vec![0.0; 5], 2, 100);
+		assert_eq!(audio.try_trim(0.2), Err(AudioBufferError::MisalignedData { sample_count: 5, channel_count: 2 }));
+		assert_eq!(audio.data.len(), 5);
+	}
+
+	#[test]
+	fn try_trim_no_channels() {
+		let mut audio:AudioBuffer = AudioBuffer::new(vec![0.0; 5], 0, 100);
+		assert_eq!(audio.try_trim_start(0.2), Err(AudioBufferError::NoChannels));
+		assert_eq!(audio.try_trim_end(0.2), Err(AudioBufferError::NoChannels));
+	}
+
+	#[test]
+	fn try_trim_with_no_channels() {
+		let mut audio:AudioBuffer = AudioBuffer::new(vec![0.0; 5], 0, 100);
+		assert_eq!(audio.try_trim_with(&TrimSettings::new(-30.0)), Err(AudioBufferError::NoChannels));
+		assert_eq!(audio.data.len(), 5);
+		assert_eq!(AudioBuffer::new(vec![0.5; 4], 2, 100).try_trimmed_with(&TrimSettings::new(-30.0)).map(|trimmed| trimmed.data.len()), Ok(4));
+	}
+
+	#[test]
+	fn trim_with_ignores_clicks() {
+		let mut data:Vec<f32> = vec![0.0; 1000];
+		data[100] = 0.9;
+		data[400..600].iter_mut().for_each(|sample| *sample = 0.5);
+		data[900] = 0.9;
+		let trimmed:AudioBuffer = AudioBuffer::new(data, 1, 1000).trimmed_with(&TrimSettings::new(-30.0).with_detection(TrimDetection::Peak, Duration::from_millis(1)));
+		assert_eq!(trimmed.data, vec![0.5; 200]);
+	}
+
+	#[test]
+	fn trim_with_rms_window() {
+		let data:Vec<f32> = [vec![0.0; 100], vec![0.5; 100], vec![0.0; 100]].concat();
+		let trimmed:AudioBuffer = AudioBuffer::new(data, 1, 1000).trimmed_with(&TrimSettings::new(-12.0).with_detection(TrimDetection::Rms, Duration::from_millis(21)));
+		assert_eq!(trimmed.frame_count(), 110);
+		let wide:AudioBuffer = AudioBuffer::new([vec![0.0; 100], vec![0.5; 100], vec![0.0; 100]].concat(), 1, 1000).trimmed_with(&TrimSettings::new(-20.0).with_detection(TrimDetection::Rms, Duration::from_millis(21)));
+		assert_eq!(wide.frame_count(), 120);
+	}
+
+	#[test]
+	fn trim_with_padding_and_fades() {
+		let data:Vec<f32> = [vec![0.0; 100], vec![0.5; 100], vec![0.0; 100]].concat();
+		let settings:TrimSettings = TrimSettings::new(-30.0).with_detection(TrimDetection::Peak, Duration::ZERO).with_padding(Duration::from_millis(10), Duration::from_millis(30)).with_fades(Duration::from_millis(20), FadeCurve::Linear);
+		let trimmed:AudioBuffer = AudioBuffer::new(data, 1, 1000).trimmed_with(&settings);
+		assert_eq!(trimmed.frame_count(), 140);
+		assert_eq!(trimmed.data[..10], [0.0; 10]);
+		assert_eq!(trimmed.data[10], 0.25);
+		assert_eq!(trimmed.data[20..110], [0.5; 90]);
+	}
+
+	#[test]
+	fn trim_with_zero_crossing_snap() {
+		let data:Vec<f32> = [vec![0.001, -0.001, 0.001, -0.001, 0.002, 0.003, 0.004], vec![0.5; 10], vec![0.004, 0.003, -0.002, 0.001]].concat();
+		let settings:TrimSettings = TrimSettings::new(-30.0).with_detection(TrimDetection::Peak, Duration::ZERO).with_min_duration(Duration::from_millis(1)).with_zero_crossing_snap(Duration::from_millis(5));
+		let trimmed:AudioBuffer = AudioBuffer::new(data.clone(), 1, 1000).trimmed_with(&settings);
+		assert_eq!(trimmed.data, data[4..19].to_vec());
+	}
+
+	#[test]
+	fn trim_with_removes_silence() {
+		let trimmed:AudioBuffer = AudioBuffer::new(vec![0.001; 100], 2, 1000).trimmed_with(&TrimSettings::new(-30.0));
+		assert!(trimmed.data.is_empty());
+	}
+}
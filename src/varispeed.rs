@@ -0,0 +1,103 @@
+use crate::{ AudioBuffer, ResampleQuality, interpolation::Interpolator };
+use std::{ collections::HashMap, time::Duration };
+
+
+
+const RATE_CUTOFF_STEPS:f32 = 16.0; // Kernels are shared between rates that are less than 1/16th apart.
+const MAX_RATE:f32 = 16.0;
+const MAX_CACHED_INTERPOLATORS:usize = 8;
+
+
+
+enum RateCurve {
+	Breakpoints(Vec<(Duration, f32)>),
+	Function(Box<dyn Fn(Duration) -> f32>)
+}
+
+
+
+pub struct RateEnvelope {
+	curve:RateCurve
+}
+impl RateEnvelope {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create an envelope with a constant playback rate.
+	pub fn constant(rate:f32) -> RateEnvelope {
+		RateEnvelope::breakpoints(vec![(Duration::ZERO, rate)])
+	}
+
+	/// Create an envelope from breakpoints of render time and playback rate. The rate is interpolated linearly between breakpoints and held before the first and after the last one.
+	pub fn breakpoints(mut breakpoints:Vec<(Duration, f32)>) -> RateEnvelope {
+		breakpoints.sort_by_key(|(time, _)| *time);
+		RateEnvelope {
+			curve: RateCurve::Breakpoints(breakpoints)
+		}
+	}
+
+	/// Create an envelope from a function that returns the playback rate at a render time.
+	pub fn function<T:Fn(Duration) -> f32 + 'static>(function:T) -> RateEnvelope {
+		RateEnvelope {
+			curve: RateCurve::Function(Box::new(function))
+		}
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the playback rate at the given render time. A rate of 1.0 plays at normal speed, negative rates play backwards.
+	pub fn rate_at(&self, time:Duration) -> f32 {
+		match &self.curve {
+			RateCurve::Function(function) => function(time),
+			RateCurve::Breakpoints(breakpoints) => {
+				let next_index:usize = breakpoints.partition_point(|(breakpoint_time, _)| *breakpoint_time <= time);
+				match (next_index.checked_sub(1).map(|index| breakpoints[index]), breakpoints.get(next_index).copied()) {
+					(None, None) => 1.0,
+					(Some((_, rate)), None) | (None, Some((_, rate))) => rate,
+					(Some((previous_time, previous_rate)), Some((next_time, next_rate))) => {
+						let fraction:f32 = ((time - previous_time).as_secs_f64() / (next_time - previous_time).as_secs_f64()) as f32;
+						previous_rate + (next_rate - previous_rate) * fraction
+					}
+				}
+			}
+		}
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Render the audio at a playback rate that changes over time, starting at the first frame. The envelope is evaluated at the render time of each output frame and limited to 16 times the normal speed in either direction, invalid rates stop playback. The source is read using band-limited interpolation, positions outside of the source are silent.
+	pub fn render_varispeed(&self, envelope:&RateEnvelope, duration:Duration) -> AudioBuffer {
+		self.render_varispeed_with_quality(envelope, duration, ResampleQuality::default())
+	}
+
+	/// Render the audio at a playback rate that changes over time using the given interpolation quality.
+	pub fn render_varispeed_with_quality(&self, envelope:&RateEnvelope, duration:Duration, quality:ResampleQuality) -> AudioBuffer {
+		let output_frame_count:usize = self.duration_to_frames(duration);
+		let last_frame_index:f64 = self.frame_count() as f64 - 1.0;
+		let mut interpolators:HashMap<u32, Interpolator> = HashMap::new();
+		let mut output:Vec<f32> = vec![0.0; output_frame_count * self.channel_count];
+		let mut position:f64 = 0.0;
+		let mut weights:Vec<(isize, f64)> = Vec::new();
+		for (frame_index, frame) in output.chunks_mut(self.channel_count).enumerate() {
+			let rate:f32 = envelope.rate_at(self.frames_to_duration(frame_index));
+			let rate:f32 = if rate.is_nan() { 0.0 } else { rate.clamp(-MAX_RATE, MAX_RATE) };
+			if position >= 0.0 && position <= last_frame_index {
+
+				// Faster playback needs a lower cutoff to prevent aliasing. Only a few kernels are kept, as a sweep only needs the ones near its current rate.
+				let cutoff_step:u32 = (rate.abs().max(1.0) * RATE_CUTOFF_STEPS).ceil() as u32;
+				if !interpolators.contains_key(&cutoff_step) && interpolators.len() >= MAX_CACHED_INTERPOLATORS {
+					interpolators.clear();
+				}
+				let interpolator:&Interpolator = interpolators.entry(cutoff_step).or_insert_with(|| Interpolator::new(quality, RATE_CUTOFF_STEPS as f64 / cutoff_step as f64));
+				interpolator.interpolate_frame(&self.data, self.channel_count, position, frame, &mut weights);
+			}
+			position += rate as f64;
+		}
+		self.with_data(output)
+	}
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+	use crate::{ AudioBuffer, RateEnvelope, ResampleQuality, test_helpers::mono_sine_wave };
+
+
+
+	#[test]
+	fn rate_envelope_interpolates_breakpoints() {
+		let envelope:RateEnvelope = RateEnvelope::breakpoints(vec![(Duration::from_secs(2), 0.0), (Duration::from_secs(1), 1.0)]);
+		assert_eq!(envelope.rate_at(Duration::ZERO), 1.0);
+		assert_eq!(envelope.rate_at(Duration::from_millis(1500)), 0.5);
+		assert_eq!(envelope.rate_at(Duration::from_secs(3)), 0.0);
+		assert_eq!(RateEnvelope::function(|time| time.as_secs_f32() * 2.0).rate_at(Duration::from_secs(2)), 4.0);
+	}
+
+	#[test]
+	fn varispeed_constant_rate_is_unchanged() {
+		let wave:AudioBuffer = mono_sine_wave(440.0, 800, 8000);
+		let rendered:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(1.0), wave.duration());
+		rendered.assert_similar(&wave, 0.00001);
+	}
+
+	#[test]
+	fn varispeed_double_rate_doubles_pitch() {
+		let wave:AudioBuffer = mono_sine_wave(440.0, 8000, 8000);
+		let rendered:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(2.0), Duration::from_millis(500));
+		assert_eq!(rendered.frame_count(), 4000);
+		let crossings:usize = rendered.data[1000..3000].windows(2).filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0).count();
+		assert!((crossings as f32 / 0.25 - 880.0).abs() < 880.0 * 0.02);
+	}
+
+	#[test]
+	fn varispeed_tape_stop() {
+		let wave:AudioBuffer = AudioBuffer::new((0..8000).map(|index| index as f32 / 8000.0).collect(), 1, 8000);
+		let envelope:RateEnvelope = RateEnvelope::breakpoints(vec![(Duration::ZERO, 1.0), (Duration::from_secs(1), 0.0)]);
+		let rendered:AudioBuffer = wave.render_varispeed_with_quality(&envelope, Duration::from_secs(2), ResampleQuality::Linear);
+		assert_eq!(rendered.frame_count(), 16000);
+		assert!((rendered.data[15999] - 0.5).abs() < 0.001);
+		assert!(rendered.data.windows(2).all(|pair| pair[1] >= pair[0]));
+	}
+
+	#[test]
+	fn varispeed_outside_source_is_silent() {
+		let wave:AudioBuffer = AudioBuffer::new(vec![0.5; 200], 2, 100);
+		let backwards:AudioBuffer = wave.render_varispeed(&RateEnvelope::function(|_| -1.0), Duration::from_secs(1));
+		assert_eq!(backwards.data[..2], [0.5, 0.5]);
+		assert!(backwards.data[2..].iter().all(|sample| *sample == 0.0));
+
+		let past_end:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(1.0), Duration::from_secs(2));
+		assert_eq!(past_end.frame_count(), 200);
+		assert!(past_end.data[200..].iter().all(|sample| *sample == 0.0));
+	}
+
+	#[test]
+	fn varispeed_limits_extreme_rates() {
+		let wave:AudioBuffer = mono_sine_wave(440.0, 800, 8000);
+		let infinite:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(f32::INFINITY), wave.duration());
+		let limited:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(16.0), wave.duration());
+		assert_eq!(infinite, limited);
+
+		let invalid:AudioBuffer = wave.render_varispeed(&RateEnvelope::constant(f32::NAN), wave.duration());
+		assert!(invalid.data.iter().all(|sample| *sample == wave.data[0]));
+
+		let sweep:AudioBuffer = wave.render_varispeed(&RateEnvelope::function(|time| 1.0 + time.as_secs_f32() * 5000.0), wave.duration());
+		assert_eq!(sweep.frame_count(), 800);
+		assert!(sweep.data.iter().all(|sample| sample.is_finite()));
+	}
+}
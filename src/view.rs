@@ -0,0 +1,226 @@
+use std::{ ops::Range, slice::{ ChunksExact, ChunksExactMut }, time::Duration };
+use crate::{ AudioBuffer, ChannelLayout };
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AudioBufferRef<'a> {
+	pub(crate) data:&'a [f32],
+	pub(crate) channel_count:usize,
+	pub(crate) sample_rate:u32,
+	pub(crate) channel_layout:Option<&'a ChannelLayout>
+}
+impl<'a> AudioBufferRef<'a> {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the data of the view.
+	pub fn data(&self) -> &'a [f32] {
+		self.data
+	}
+
+	/// Get the channel count of the view.
+	pub fn channel_count(&self) -> usize {
+		self.channel_count
+	}
+
+	/// Get the sample rate of the view.
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Get the channel layout of the view, if it is known.
+	pub fn channel_layout(&self) -> Option<&'a ChannelLayout> {
+		self.channel_layout
+	}
+
+	/// Get the amount of frames in the view.
+	pub fn frame_count(&self) -> usize {
+		self.data.len() / self.channel_count
+	}
+
+	/// Get the duration of the view.
+	pub fn duration(&self) -> Duration {
+		Duration::from_secs_f64(self.frame_count() as f64 / self.sample_rate as f64)
+	}
+
+	/// Iterate over all frames.
+	pub fn frames(&self) -> ChunksExact<'a, f32> {
+		self.data.chunks_exact(self.channel_count)
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Create a smaller view of a range of frames within this view. The range is limited to the available frames.
+	pub fn view_frames(&self, range:Range<usize>) -> AudioBufferRef<'a> {
+		AudioBufferRef {
+			data: &self.data[frame_range_to_sample_range(range, self.channel_count, self.data.len())],
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout
+		}
+	}
+
+	/// Copy the viewed data into a new audio buffer.
+	pub fn to_buffer(&self) -> AudioBuffer {
+		AudioBuffer {
+			data: self.data.to_vec(),
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout.cloned(),
+			loop_range: None
+		}
+	}
+}
+
+
+
+#[derive(PartialEq, Debug)]
+pub struct AudioBufferMut<'a> {
+	pub(crate) data:&'a mut [f32],
+	pub(crate) channel_count:usize,
+	pub(crate) sample_rate:u32,
+	pub(crate) channel_layout:Option<&'a ChannelLayout>
+}
+impl AudioBufferMut<'_> {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the data of the view.
+	pub fn data(&self) -> &[f32] {
+		self.data
+	}
+
+	/// Get the data of the view mutably.
+	pub fn data_mut(&mut self) -> &mut [f32] {
+		self.data
+	}
+
+	/// Get the channel count of the view.
+	pub fn channel_count(&self) -> usize {
+		self.channel_count
+	}
+
+	/// Get the sample rate of the view.
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Get the channel layout of the view, if it is known.
+	pub fn channel_layout(&self) -> Option<&ChannelLayout> {
+		self.channel_layout
+	}
+
+	/// Get the amount of frames in the view.
+	pub fn frame_count(&self) -> usize {
+		self.data.len() / self.channel_count
+	}
+
+	/// Get the duration of the view.
+	pub fn duration(&self) -> Duration {
+		self.as_view().duration()
+	}
+
+	/// Iterate over all frames.
+	pub fn frames(&self) -> ChunksExact<'_, f32> {
+		self.data.chunks_exact(self.channel_count)
+	}
+
+	/// Iterate over all frames mutably.
+	pub fn frames_mut(&mut self) -> ChunksExactMut<'_, f32> {
+		self.data.chunks_exact_mut(self.channel_count)
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get an immutable view of the same data.
+	pub fn as_view(&self) -> AudioBufferRef<'_> {
+		AudioBufferRef {
+			data: self.data,
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout
+		}
+	}
+
+	/// Create a smaller mutable view of a range of frames within this view. The range is limited to the available frames.
+	pub fn view_frames_mut(&mut self, range:Range<usize>) -> AudioBufferMut<'_> {
+		let sample_range:Range<usize> = frame_range_to_sample_range(range, self.channel_count, self.data.len());
+		AudioBufferMut {
+			data: &mut self.data[sample_range],
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout
+		}
+	}
+
+	/// Copy the viewed data into a new audio buffer.
+	pub fn to_buffer(&self) -> AudioBuffer {
+		self.as_view().to_buffer()
+	}
+}
+
+
+
+impl AudioBuffer {
+
+	/// Get an immutable view of the entire buffer.
+	pub fn as_view(&self) -> AudioBufferRef<'_> {
+		AudioBufferRef {
+			data: &self.data,
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout.as_ref()
+		}
+	}
+
+	/// Get a mutable view of the entire buffer.
+	pub fn as_view_mut(&mut self) -> AudioBufferMut<'_> {
+		AudioBufferMut {
+			data: &mut self.data,
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout.as_ref()
+		}
+	}
+
+	/// Get an immutable view of a range of frames. The range is limited to the available frames.
+	pub fn view_frames(&self, range:Range<usize>) -> AudioBufferRef<'_> {
+		self.as_view().view_frames(range)
+	}
+
+	/// Get a mutable view of a range of frames. The range is limited to the available frames.
+	pub fn view_frames_mut(&mut self, range:Range<usize>) -> AudioBufferMut<'_> {
+		let sample_range:Range<usize> = frame_range_to_sample_range(range, self.channel_count, self.data.len());
+		AudioBufferMut {
+			data: &mut self.data[sample_range],
+			channel_count: self.channel_count,
+			sample_rate: self.sample_rate,
+			channel_layout: self.channel_layout.as_ref()
+		}
+	}
+
+	/// Get an immutable view of a time range. The range is limited to the duration of the buffer.
+	pub fn view_time(&self, range:Range<Duration>) -> AudioBufferRef<'_> {
+		self.view_frames(self.time_range_to_frame_range(range))
+	}
+
+	/// Get a mutable view of a time range. The range is limited to the duration of the buffer.
+	pub fn view_time_mut(&mut self, range:Range<Duration>) -> AudioBufferMut<'_> {
+		let frame_range:Range<usize> = self.time_range_to_frame_range(range);
+		self.view_frames_mut(frame_range)
+	}
+}
+
+
+
+/// Convert a range of frames to a range of indexes in interleaved data, limited to the available data.
+pub(crate) fn frame_range_to_sample_range(range:Range<usize>, channel_count:usize, data_len:usize) -> Range<usize> {
+	let end:usize = (range.end * channel_count).min(data_len);
+	let start:usize = (range.start * channel_count).min(end);
+	start..end
+}
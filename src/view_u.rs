@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod test {
+	use crate::{ AudioBuffer, AudioBufferMut, AudioBufferRef };
+	use std::time::Duration;
+
+
+
+	fn stereo_counting_buffer() -> AudioBuffer {
+		AudioBuffer::new((0..200).map(|index| index as f32).collect(), 2, 1000)
+	}
+
+
+
+	#[test]
+	fn view_frames() {
+		let buffer:AudioBuffer = stereo_counting_buffer();
+		let view:AudioBufferRef = buffer.view_frames(10..20);
+		assert_eq!(view.data(), &buffer.data[20..40]);
+		assert_eq!(view.channel_count(), 2);
+		assert_eq!(view.sample_rate(), 1000);
+		assert_eq!(view.frame_count(), 10);
+		assert_eq!(view.duration(), Duration::from_millis(10));
+		assert_eq!(view.view_frames(5..100).data(), &buffer.data[30..40]);
+		assert_eq!(view.to_buffer(), buffer.slice_frames(10..20));
+	}
+
+	#[test]
+	fn view_time() {
+		let buffer:AudioBuffer = stereo_counting_buffer();
+		assert_eq!(buffer.view_time(Duration::from_millis(50)..Duration::from_millis(200)), buffer.view_frames(50..100));
+	}
+
+	#[test]
+	fn view_frames_mut() {
+		let mut buffer:AudioBuffer = stereo_counting_buffer();
+		let mut view:AudioBufferMut = buffer.view_frames_mut(90..100);
+		view.frames_mut().for_each(|frame| frame.fill(0.0));
+		view.view_frames_mut(0..1).data_mut()[0] = -1.0;
+
+		assert_eq!(buffer.data[..180], (0..180).map(|index| index as f32).collect::<Vec<f32>>());
+		assert_eq!(buffer.data[180], -1.0);
+		assert_eq!(buffer.data[181..], [0.0; 19]);
+	}
+}
@@ -1,7 +1,7 @@
 use bytes_parser::BytesParser;
-use crate::AudioBuffer;
+use crate::{ AudioBuffer, AudioBufferRef, ChannelLayout };
 use file_ref::FileRef;
-use std::error::Error;
+use std::{ error::Error, ops::Range };
 
 
 
@@ -9,6 +9,10 @@ const MAX_CHUNK_BYTES:u32 = 1024 * 1024 * 1024; // 1GB
 const DEFAULT_SAMPLE_RATE:u32 = 48_000;
 const DEFAULT_CHANEL_COUNT:usize = 2;
 pub(super) const IS_BIG_ENDIAN:bool = false;
+const EXTENSIBLE_AUDIO_FORMAT:u16 = 0xFFFE;
+const SAMPLER_MIDI_UNITY_NOTE:u32 = 60;
+const SAMPLER_FORWARD_LOOP_TYPE:u32 = 0;
+const FLOAT_SUB_FORMAT_GUID_TAIL:[u8; 14] = [0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71];
 
 
 pub(super) const RIFF_IDENTIFIER:[u8; 4] = [0x52, 0x49, 0x46, 0x46];
@@ -16,13 +20,15 @@ pub(super) const WAVE_IDENTIFIER:[u8; 4] = [0x57, 0x41, 0x56, 0x45];
 pub(super) const JUNK_IDENTIFIER:[u8; 4] = [0x4A, 0x55, 0x4E, 0x4B];
 pub(super) const DATA_FORMAT_IDENTIFIER:[u8; 4] = [0x66, 0x6D, 0x74, 0x20];
 pub(super) const SAMPLED_DATA_IDENTIFIER:[u8; 4] = [0x64, 0x61, 0x74, 0x61];
+pub(super) const SAMPLER_IDENTIFIER:[u8; 4] = [0x73, 0x6D, 0x70, 0x6C];
 
 
 
 pub(super) struct DataFormat {
 	audio_format:u16, // 1: PCM integer, 3: IEEE 754 float
 	channel_count:u16,
-	sample_rate:u32
+	sample_rate:u32,
+	channel_mask:Option<u32>
 }
 
 impl AudioBuffer {
@@ -37,6 +43,7 @@ impl AudioBuffer {
 		let mut parser:BytesParser = BytesParser::new(bytes, IS_BIG_ENDIAN);
 		let mut data_format:Option<DataFormat> = None;
 		let mut audio_data:Vec<f32> = Vec::new();
+		let mut loop_range:Option<Range<usize>> = None;
 		
 		// Parse Master RIFF and WAVE identifier.
 		if parser.take::<[u8; 4]>()? != RIFF_IDENTIFIER {
@@ -48,71 +55,39 @@ impl AudioBuffer {
 		}
 
 		// Keep parsing chunks as long as possible.
-		while Self::parse_any_chunk(&mut parser, &mut data_format, &mut audio_data)? {}
+		while Self::parse_any_chunk(&mut parser, &mut data_format, &mut audio_data, &mut loop_range)? {}
 
 		// Return full wav.
-		Ok(AudioBuffer::new(
+		let mut buffer:AudioBuffer = AudioBuffer::new(
 			audio_data,
 			data_format.as_ref().map(|format| format.channel_count as usize).unwrap_or(DEFAULT_CHANEL_COUNT),
 			data_format.as_ref().map(|format| format.sample_rate).unwrap_or(DEFAULT_SAMPLE_RATE)
-		))
+		);
+		let channel_layout:Option<ChannelLayout> = data_format.as_ref().and_then(|format| format.channel_mask).and_then(ChannelLayout::from_mask);
+		if channel_layout.as_ref().map(|layout| layout.channel_count() == buffer.channel_count).unwrap_or(false) {
+			buffer.channel_layout = channel_layout;
+		}
+		let frame_count:usize = buffer.frame_count();
+		buffer.loop_range = loop_range.map(|range| range.start.min(frame_count)..range.end.min(frame_count)).filter(|range| !range.is_empty());
+		Ok(buffer)
 	}
 
-	/// Store the audio buffer to a WAV.
+	/// Store the audio buffer to a WAV. The loop range is stored in the sampler chunk. Channel layouts that a WAV channel mask can not describe, like custom layouts with speakers out of the standard order, are not stored.
 	pub fn to_wav(&self, file_path:&str) -> Result<(), Box<dyn Error>> {
-		
-		// DataFormat block.
-		let audio_format:u16 = 3;
-		let channel_count:u16 = self.channel_count as u16;
-		let sample_rate:u32 = self.sample_rate;
-		let bits_per_sample:u16 = 4 * 8; // f32 has 4 bytes
-		let bytes_per_block:u16 = self.channel_count as u16 * bits_per_sample / 8;
-		let bytes_per_second:u32 = self.sample_rate * bytes_per_block as u32;
-		let data_format_chunk:Vec<u8> = [
-			DATA_FORMAT_IDENTIFIER.to_vec(),
-			16_u32.to_le_bytes().to_vec(),
-			audio_format.to_le_bytes().to_vec(),
-			channel_count.to_le_bytes().to_vec(),
-			sample_rate.to_le_bytes().to_vec(),
-			bytes_per_second.to_le_bytes().to_vec(),
-			bytes_per_block.to_le_bytes().to_vec(),
-			bits_per_sample.to_le_bytes().to_vec()
-		].into_iter().flatten().collect();
-
-		// Audio data chunks.
-		let block_size:u32 = (self.data.len() as u32 * 4).min(MAX_CHUNK_BYTES);
-		let audio_data_chunks:Vec<Vec<u8>> = self.data.chunks(block_size as usize).map(|audio_chunk| [
-			SAMPLED_DATA_IDENTIFIER.to_vec(),
-			((audio_chunk.len() * 4) as u32).to_le_bytes().to_vec(),
-			audio_chunk.iter().map(|item| item.to_le_bytes()).flatten().collect::<Vec<u8>>()
-		]).flatten().collect();
-
-		// Master riff chunk.
-		let master_riff_chunk:Vec<u8> = [
-			RIFF_IDENTIFIER.to_vec(),
-			((
-				data_format_chunk.len() +
-				audio_data_chunks.iter().map(|chunk| chunk.len()).sum::<usize>() + 
-				WAVE_IDENTIFIER.len()
-			) as u32).to_le_bytes().to_vec(),
-			WAVE_IDENTIFIER.to_vec()
-		].into_iter().flatten().collect();
+		FileRef::new(file_path).write_bytes(&self.wav_bytes())
+	}
 
-		// Combine chunks and write to file.
-		let total_bytes:Vec<u8> = [
-			vec![master_riff_chunk],
-			vec![data_format_chunk],
-			audio_data_chunks
-		].into_iter().flatten().flatten().collect();
-		FileRef::new(file_path).write_bytes(&total_bytes)
+	/// Create wav-contents from the audio, including the loop range.
+	pub(crate) fn wav_bytes(&self) -> Vec<u8> {
+		self.as_view().wav_bytes_with_loop(self.loop_range.clone())
 	}
 
 
 
 	/* PARSING METHODS */
 
-	/// Try to parse any chunk. Returns true if a chunk was successfully parsed and added.
-	pub(super) fn parse_any_chunk(parser:&mut BytesParser, data_format:&mut Option<DataFormat>, audio_data:&mut Vec<f32>) -> Result<bool, Box<dyn Error>> {
+	/// Try to parse any chunk. Returns true if a chunk was successfully parsed and added, unknown chunks are skipped by their size. A malformed sampler chunk is ignored, as it only holds the loop range.
+	pub(super) fn parse_any_chunk(parser:&mut BytesParser, data_format:&mut Option<DataFormat>, audio_data:&mut Vec<f32>, loop_range:&mut Option<Range<usize>>) -> Result<bool, Box<dyn Error>> {
 		if let Ok(identifier) = parser.take::<[u8; 4]>() {
 			let block_size:u32 = parser.take::<u32>()?;
 			let mut sub_parser:BytesParser = BytesParser::new(parser.take_bytes(block_size as usize)?, IS_BIG_ENDIAN);
@@ -122,8 +97,9 @@ impl AudioBuffer {
 			match identifier {
 				DATA_FORMAT_IDENTIFIER => Self::parse_data_format_chunk(&mut sub_parser, data_format),
 				SAMPLED_DATA_IDENTIFIER => Self::parse_sampled_data(&mut sub_parser, data_format, audio_data),
+				SAMPLER_IDENTIFIER => Ok(Self::parse_sampler_chunk(&mut sub_parser, loop_range).unwrap_or(true)),
 				JUNK_IDENTIFIER => Ok(true),
-				_ => Ok(false)
+				_ => Ok(true) // Unknown chunks like LIST and cue.
 			}
 		} else {
 			Ok(false)
@@ -132,10 +108,23 @@ impl AudioBuffer {
 
 	/// Try to parse the Main RIFF. Returns true if the chunk was parsed and added.
 	fn parse_data_format_chunk(parser:&mut BytesParser, data_format:&mut Option<DataFormat>) -> Result<bool, Box<dyn Error>> {
-		let audio_format:u16 = parser.take()?; // 1: u16, 3: f32
+		let mut audio_format:u16 = parser.take()?; // 1: u16, 3: f32, 0xFFFE: extensible
 		let channel_count:u16 = parser.take()?;
 		let sample_rate:u32 = parser.take()?;
-		*data_format = Some(DataFormat { audio_format, channel_count, sample_rate });
+		let mut channel_mask:Option<u32> = None;
+
+		// Extensible format stores the channel mask and actual audio format after the basic format.
+		if audio_format == EXTENSIBLE_AUDIO_FORMAT {
+			let _bytes_per_second:u32 = parser.take()?;
+			let _bytes_per_block:u16 = parser.take()?;
+			let _bits_per_sample:u16 = parser.take()?;
+			let _extension_size:u16 = parser.take()?;
+			let _valid_bits_per_sample:u16 = parser.take()?;
+			channel_mask = Some(parser.take()?);
+			audio_format = parser.take()?;
+		}
+
+		*data_format = Some(DataFormat { audio_format, channel_count, sample_rate, channel_mask });
 		Ok(true)
 	}
 
@@ -166,4 +155,138 @@ impl AudioBuffer {
 		// Return success.
 		Ok(true)
 	}
+
+	/// Try to parse the sampler chunk. The first loop is used as loop range. Returns true if the chunk was parsed.
+	fn parse_sampler_chunk(parser:&mut BytesParser, loop_range:&mut Option<Range<usize>>) -> Result<bool, Box<dyn Error>> {
+		let _manufacturer:u32 = parser.take()?;
+		let _product:u32 = parser.take()?;
+		let _sample_period:u32 = parser.take()?;
+		let _midi_unity_note:u32 = parser.take()?;
+		let _midi_pitch_fraction:u32 = parser.take()?;
+		let _smpte_format:u32 = parser.take()?;
+		let _smpte_offset:u32 = parser.take()?;
+		let loop_count:u32 = parser.take()?;
+		let _sampler_data_size:u32 = parser.take()?;
+		if loop_count > 0 {
+			let _cue_point_id:u32 = parser.take()?;
+			let _loop_type:u32 = parser.take()?;
+			let start:u32 = parser.take()?;
+			let end:u32 = parser.take()?; // Inclusive.
+			if start <= end {
+				*loop_range = Some(start as usize..end as usize + 1);
+			}
+		}
+		Ok(true)
+	}
+}
+
+
+
+impl AudioBufferRef<'_> {
+
+	/// Store the viewed audio to a WAV. Channel layouts that a WAV channel mask can not describe are not stored.
+	pub fn to_wav(&self, file_path:&str) -> Result<(), Box<dyn Error>> {
+		FileRef::new(file_path).write_bytes(&self.wav_bytes())
+	}
+
+	/// Create wav-contents from the viewed audio.
+	pub(crate) fn wav_bytes(&self) -> Vec<u8> {
+		self.wav_bytes_with_loop(None)
+	}
+
+	/// Create wav-contents from the viewed audio. A loop range is stored in a sampler chunk, limited to the available frames.
+	pub(crate) fn wav_bytes_with_loop(&self, loop_range:Option<Range<usize>>) -> Vec<u8> {
+		
+		// DataFormat block.
+		let audio_format:u16 = 3;
+		let channel_count:u16 = self.channel_count as u16;
+		let sample_rate:u32 = self.sample_rate;
+		let bits_per_sample:u16 = 4 * 8; // f32 has 4 bytes
+		let bytes_per_block:u16 = self.channel_count as u16 * bits_per_sample / 8;
+		let bytes_per_second:u32 = self.sample_rate * bytes_per_block as u32;
+		let data_format_chunk:Vec<u8> = match self.channel_layout.and_then(|channel_layout| channel_layout.mask()) {
+			None => [
+				DATA_FORMAT_IDENTIFIER.to_vec(),
+				16_u32.to_le_bytes().to_vec(),
+				audio_format.to_le_bytes().to_vec(),
+				channel_count.to_le_bytes().to_vec(),
+				sample_rate.to_le_bytes().to_vec(),
+				bytes_per_second.to_le_bytes().to_vec(),
+				bytes_per_block.to_le_bytes().to_vec(),
+				bits_per_sample.to_le_bytes().to_vec()
+			].into_iter().flatten().collect(),
+
+			// Use the extensible format to store the channel layout.
+			Some(channel_mask) => [
+				DATA_FORMAT_IDENTIFIER.to_vec(),
+				40_u32.to_le_bytes().to_vec(),
+				EXTENSIBLE_AUDIO_FORMAT.to_le_bytes().to_vec(),
+				channel_count.to_le_bytes().to_vec(),
+				sample_rate.to_le_bytes().to_vec(),
+				bytes_per_second.to_le_bytes().to_vec(),
+				bytes_per_block.to_le_bytes().to_vec(),
+				bits_per_sample.to_le_bytes().to_vec(),
+				22_u16.to_le_bytes().to_vec(),
+				bits_per_sample.to_le_bytes().to_vec(),
+				channel_mask.to_le_bytes().to_vec(),
+				audio_format.to_le_bytes().to_vec(),
+				FLOAT_SUB_FORMAT_GUID_TAIL.to_vec()
+			].into_iter().flatten().collect()
+		};
+
+		// Audio data chunks, split at whole frames. Empty audio is stored as a single empty chunk.
+		let max_chunk_sample_count:usize = (MAX_CHUNK_BYTES as usize / 4 / self.channel_count * self.channel_count).max(self.channel_count);
+		let audio_chunks:Vec<&[f32]> = if self.data.is_empty() { vec![self.data] } else { self.data.chunks(max_chunk_sample_count).collect() };
+		let audio_data_chunks:Vec<Vec<u8>> = audio_chunks.into_iter().map(|audio_chunk| [
+			SAMPLED_DATA_IDENTIFIER.to_vec(),
+			((audio_chunk.len() * 4) as u32).to_le_bytes().to_vec(),
+			audio_chunk.iter().map(|item| item.to_le_bytes()).flatten().collect::<Vec<u8>>()
+		]).flatten().collect();
+
+		// Sampler chunk with a single forward loop. The loop end is inclusive.
+		let frame_count:usize = self.data.len() / self.channel_count;
+		let sampler_chunk:Vec<u8> = match loop_range.map(|range| range.start.min(frame_count)..range.end.min(frame_count)).filter(|range| !range.is_empty()) {
+			None => Vec::new(),
+			Some(loop_range) => {
+				let sampler_body:Vec<u8> = [
+					0_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					1_000_000_000_u32.checked_div(self.sample_rate).unwrap_or(0).to_le_bytes().to_vec(),
+					SAMPLER_MIDI_UNITY_NOTE.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					1_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					SAMPLER_FORWARD_LOOP_TYPE.to_le_bytes().to_vec(),
+					(loop_range.start as u32).to_le_bytes().to_vec(),
+					(loop_range.end as u32 - 1).to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec(),
+					0_u32.to_le_bytes().to_vec()
+				].into_iter().flatten().collect();
+				[SAMPLER_IDENTIFIER.to_vec(), (sampler_body.len() as u32).to_le_bytes().to_vec(), sampler_body].concat()
+			}
+		};
+
+		// Master riff chunk.
+		let master_riff_chunk:Vec<u8> = [
+			RIFF_IDENTIFIER.to_vec(),
+			((
+				data_format_chunk.len() +
+				audio_data_chunks.iter().map(|chunk| chunk.len()).sum::<usize>() + 
+				sampler_chunk.len() +
+				WAVE_IDENTIFIER.len()
+			) as u32).to_le_bytes().to_vec(),
+			WAVE_IDENTIFIER.to_vec()
+		].into_iter().flatten().collect();
+
+		// Combine chunks.
+		[
+			vec![master_riff_chunk],
+			vec![data_format_chunk],
+			audio_data_chunks,
+			vec![sampler_chunk]
+		].into_iter().flatten().flatten().collect()
+	}
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
-	use crate::{ AudioBuffer, wav_parser::wav_parser::{ DATA_FORMAT_IDENTIFIER, DataFormat, IS_BIG_ENDIAN, JUNK_IDENTIFIER, RIFF_IDENTIFIER, SAMPLED_DATA_IDENTIFIER, WAVE_IDENTIFIER } };
+	use crate::{ AudioBuffer, ChannelLayout, Speaker, wav_parser::wav_parser::{ DATA_FORMAT_IDENTIFIER, DataFormat, IS_BIG_ENDIAN, JUNK_IDENTIFIER, RIFF_IDENTIFIER, SAMPLED_DATA_IDENTIFIER, WAVE_IDENTIFIER } };
 	use bytes_parser::BytesParser;
+	use std::ops::Range;
 
 
 	const RIFF_HEADER_SIZE: usize = 12; // "RIFF" + size + "WAVE".
@@ -56,6 +57,7 @@ mod tests {
 		let mut parser:BytesParser = BytesParser::new(bytes, IS_BIG_ENDIAN);
 		let mut data_format:Option<DataFormat> = None;
 		let mut audio:Vec<f32> = Vec::new();
+		let mut loop_range:Option<Range<usize>> = None;
 
 		// Parse RIFF.
 		assert_eq!(parser.take::<[u8;4]>().unwrap(), *b"RIFF");
@@ -64,26 +66,107 @@ mod tests {
 		assert_eq!(parser.cursor(), RIFF_HEADER_SIZE);
 
 		// Parse DataFormat.
-		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio).unwrap());
+		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio, &mut loop_range).unwrap());
 		assert_eq!(parser.cursor(), RIFF_HEADER_SIZE + FMT_CHUNK_SIZE);
 
 		// Parse Junk (odd padded).
-		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio).unwrap());
+		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio, &mut loop_range).unwrap());
 		assert_eq!(parser.cursor(), RIFF_HEADER_SIZE + FMT_CHUNK_SIZE + JUNK_CHUNK_SIZE);
 
 		// Parse first data chunk.
-		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio).unwrap());
+		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio, &mut loop_range).unwrap());
 		assert_eq!(parser.cursor(), RIFF_HEADER_SIZE + FMT_CHUNK_SIZE + JUNK_CHUNK_SIZE + DATA1_CHUNK_SIZE);
 
 		// Parse second data chunk (odd padded).
-		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio).unwrap());
+		assert!(AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio, &mut loop_range).unwrap());
 		assert_eq!(parser.cursor(), RIFF_HEADER_SIZE + FMT_CHUNK_SIZE + JUNK_CHUNK_SIZE + DATA1_CHUNK_SIZE + DATA2_CHUNK_SIZE);
 
 		// Do not error when the data runs out.
-		assert!(!AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio).unwrap());
+		assert!(!AudioBuffer::parse_any_chunk(&mut parser, &mut data_format, &mut audio, &mut loop_range).unwrap());
 
 		// Audio length correctness.
 		assert_eq!(audio.len(), 3);
 	}
 
-}
\ No newline at end of file
+	#[test]
+	fn view_round_trip() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32 * 0.01).collect(), 2, 44100);
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.view_frames(10..30).wav_bytes()).unwrap();
+		assert_eq!(parsed, buffer.slice_frames(10..30));
+	}
+
+	#[test]
+	fn empty_view_round_trip() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32 * 0.01).collect(), 2, 44100);
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.view_frames(20..20).wav_bytes()).unwrap();
+		assert_eq!(parsed, AudioBuffer::new(Vec::new(), 2, 44100));
+	}
+
+	#[test]
+	fn channel_layout_round_trip() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..120).map(|index| index as f32 * 0.01).collect(), 6, 48000).with_channel_layout(ChannelLayout::Surround51);
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.as_view().wav_bytes()).unwrap();
+		assert_eq!(parsed, buffer);
+	}
+
+	#[test]
+	fn loop_range_round_trip() {
+		let buffer:AudioBuffer = AudioBuffer::new((0..100).map(|index| index as f32 * 0.01).collect(), 2, 44100).with_loop_range(10..40);
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.wav_bytes()).unwrap();
+		assert_eq!(parsed.loop_range(), Some(10..40));
+		assert_eq!(parsed, buffer);
+
+		let clamped:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.clone().with_loop_range(30..80).wav_bytes()).unwrap();
+		assert_eq!(clamped.loop_range(), Some(30..50));
+		assert_eq!(AudioBuffer::from_wav_bytes(buffer.as_view().wav_bytes()).unwrap().loop_range(), None);
+	}
+
+	#[test]
+	fn sampler_chunk_validation() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 2, 44100).with_loop_range(10..40);
+		let bytes:Vec<u8> = buffer.wav_bytes();
+		let loop_start_index:usize = bytes.windows(4).position(|window| window == b"smpl").unwrap() + 8 + 44;
+		let with_loop = |start:u32, end:u32| {
+			let mut bytes:Vec<u8> = bytes.clone();
+			bytes[loop_start_index..loop_start_index + 4].copy_from_slice(&start.to_le_bytes());
+			bytes[loop_start_index + 4..loop_start_index + 8].copy_from_slice(&end.to_le_bytes());
+			AudioBuffer::from_wav_bytes(bytes).unwrap().loop_range()
+		};
+		assert_eq!(with_loop(20, 29), Some(20..30));
+		assert_eq!(with_loop(30, 20), None);
+		assert_eq!(with_loop(40, 1000), Some(40..50));
+		assert_eq!(with_loop(60, 1000), None);
+
+		let size_index:usize = loop_start_index - 44 - 4;
+		assert_eq!(u32::from_le_bytes(bytes[size_index..size_index + 4].try_into().unwrap()), 60);
+		assert!(!AudioBuffer::new(vec![0.0; 10], 1, 0).with_loop_range(2..4).wav_bytes().is_empty());
+	}
+
+	#[test]
+	fn skips_unknown_and_malformed_chunks() {
+		let buffer:AudioBuffer = AudioBuffer::new(vec![0.0; 100], 2, 44100).with_loop_range(10..40);
+		let list_chunk:Vec<u8> = [&b"LIST"[..], &(5_u32).to_le_bytes(), &[1, 2, 3, 4, 5, 0]].concat();
+		let mut bytes:Vec<u8> = buffer.wav_bytes();
+		bytes.splice(RIFF_HEADER_SIZE..RIFF_HEADER_SIZE, list_chunk);
+		assert_eq!(AudioBuffer::from_wav_bytes(bytes).unwrap(), buffer);
+
+		let truncated_sampler_chunk:Vec<u8> = [&b"smpl"[..], &(8_u32).to_le_bytes(), &[0; 8]].concat();
+		let bytes:Vec<u8> = [buffer.as_view().wav_bytes(), truncated_sampler_chunk].concat();
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(bytes).unwrap();
+		assert_eq!(parsed.frame_count(), 50);
+		assert_eq!(parsed.loop_range(), None);
+	}
+
+	#[test]
+	fn unmaskable_layout_round_trip() {
+		let layout:ChannelLayout = ChannelLayout::Custom(vec![Speaker::BackCenter, Speaker::FrontCenter]);
+		assert_eq!(layout.mask(), None);
+		let buffer:AudioBuffer = AudioBuffer::new((0..40).map(|index| index as f32 * 0.01).collect(), 2, 48000).with_channel_layout(layout);
+		let parsed:AudioBuffer = AudioBuffer::from_wav_bytes(buffer.wav_bytes()).unwrap();
+		assert_eq!(parsed.channel_layout(), None);
+		assert_eq!(parsed.data(), buffer.data());
+
+		let maskable:AudioBuffer = buffer.with_channel_layout(ChannelLayout::Custom(vec![Speaker::FrontCenter, Speaker::BackCenter]));
+		assert_eq!(AudioBuffer::from_wav_bytes(maskable.wav_bytes()).unwrap(), maskable);
+	}
+}